@@ -16,6 +16,41 @@ pub async fn get_all_views(pool: &sqlx::postgres::PgPool) -> anyhow::Result<Vec<
     Ok(rows)
 }
 
+pub async fn get_all_view_table_usage(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::ViewTableUsage>> {
+    let rows: Vec<schema::ViewTableUsage> =
+        sqlx::query_as(r#"select * from information_schema.view_table_usage"#)
+            .fetch_all(pool)
+            .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_triggers(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::Trigger>> {
+    let rows: Vec<schema::Trigger> = sqlx::query_as(
+        r#"
+        select
+            n.nspname as trigger_schema,
+            t.tgname as trigger_name,
+            n.nspname as event_object_schema,
+            c.relname as event_object_table,
+            pg_catalog.pg_get_triggerdef(t.oid, true) as definition,
+            t.tgparentid <> 0 as is_partition_clone
+        from pg_catalog.pg_trigger t
+        join pg_catalog.pg_class c on c.oid = t.tgrelid
+        join pg_catalog.pg_namespace n on n.oid = c.relnamespace
+        where not t.tgisinternal
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
 pub async fn get_all_columns(pool: &sqlx::postgres::PgPool) -> anyhow::Result<Vec<schema::Column>> {
     let rows: Vec<schema::Column> = sqlx::query_as(r#"select * from information_schema.columns"#)
         .fetch_all(pool)
@@ -68,6 +103,17 @@ pub async fn get_all_constraint_table_usage(
     Ok(rows)
 }
 
+pub async fn get_all_referential_constraints(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::ReferentialConstraint>> {
+    let rows: Vec<schema::ReferentialConstraint> =
+        sqlx::query_as(r#"select * from information_schema.referential_constraints"#)
+            .fetch_all(pool)
+            .await?;
+
+    Ok(rows)
+}
+
 pub async fn get_all_element_types(
     pool: &sqlx::postgres::PgPool,
 ) -> anyhow::Result<Vec<schema::ElementType>> {
@@ -90,6 +136,1162 @@ pub async fn get_all_check_constraints(
     Ok(rows)
 }
 
+pub async fn get_all_enums(pool: &sqlx::postgres::PgPool) -> anyhow::Result<Vec<schema::Enum>> {
+    let rows: Vec<schema::Enum> = sqlx::query_as(
+        r#"
+        select
+            n.nspname as type_schema,
+            t.typname as type_name
+        from pg_catalog.pg_type t
+        join pg_catalog.pg_namespace n on n.oid = t.typnamespace
+        where t.typtype = 'e'
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_enum_labels(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::EnumLabel>> {
+    let rows: Vec<schema::EnumLabel> = sqlx::query_as(
+        r#"
+        select
+            n.nspname as type_schema,
+            t.typname as type_name,
+            e.enumlabel as label,
+            e.enumsortorder as sort_order
+        from pg_catalog.pg_enum e
+        join pg_catalog.pg_type t on t.oid = e.enumtypid
+        join pg_catalog.pg_namespace n on n.oid = t.typnamespace
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_composite_types(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::CompositeType>> {
+    let rows: Vec<schema::CompositeType> = sqlx::query_as(
+        r#"
+        select
+            n.nspname as type_schema,
+            t.typname as type_name
+        from pg_catalog.pg_type t
+        join pg_catalog.pg_namespace n on n.oid = t.typnamespace
+        join pg_catalog.pg_class c on c.oid = t.typrelid
+        where t.typtype = 'c' and c.relkind = 'c'
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_composite_type_attributes(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::CompositeTypeAttribute>> {
+    // a domain over an array is in the array category too, but has no element
+    // type of its own, so such attributes depend on the domain itself
+    let rows: Vec<schema::CompositeTypeAttribute> = sqlx::query_as(
+        r#"
+        select
+            n.nspname as type_schema,
+            t.typname as type_name,
+            a.attname as attribute_name,
+            a.attnum::int as ordinal_position,
+            pg_catalog.format_type(a.atttypid, a.atttypmod) as data_type,
+            dtn.nspname as attribute_type_schema,
+            dt.typname as attribute_type_name
+        from pg_catalog.pg_type t
+        join pg_catalog.pg_namespace n on n.oid = t.typnamespace
+        join pg_catalog.pg_class c on c.oid = t.typrelid
+        join pg_catalog.pg_attribute a on a.attrelid = c.oid
+        join pg_catalog.pg_type at on at.oid = a.atttypid
+        join pg_catalog.pg_type dt
+            on dt.oid = case
+                when at.typcategory = 'A' and at.typelem <> 0 then at.typelem
+                else at.oid
+            end
+        join pg_catalog.pg_namespace dtn on dtn.oid = dt.typnamespace
+        where t.typtype = 'c'
+            and c.relkind = 'c'
+            and a.attnum > 0
+            and not a.attisdropped
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_column_attributes(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::ColumnAttribute>> {
+    // the type modifier of an array column applies to its elements, so the
+    // element details are decoded with the same helpers information_schema
+    // uses for columns.
+    let rows: Vec<schema::ColumnAttribute> = sqlx::query_as(
+        r#"
+        select
+            n.nspname as table_schema,
+            c.relname as table_name,
+            a.attname as column_name,
+            a.attndims as array_dimensions,
+            information_schema._pg_char_max_length(et.oid, a.atttypmod)
+                as element_character_maximum_length,
+            information_schema._pg_numeric_precision(et.oid, a.atttypmod)
+                as element_numeric_precision,
+            information_schema._pg_numeric_scale(et.oid, a.atttypmod)
+                as element_numeric_scale,
+            information_schema._pg_datetime_precision(et.oid, a.atttypmod)
+                as element_datetime_precision,
+            information_schema._pg_interval_type(et.oid, a.atttypmod)
+                as element_interval_type,
+            a.attislocal as is_local,
+            a.attinhcount as inherited_count,
+            a.attcollation = t.typcollation as has_default_collation,
+            a.attnotnull as is_not_null
+        from pg_catalog.pg_attribute a
+        join pg_catalog.pg_class c on c.oid = a.attrelid
+        join pg_catalog.pg_namespace n on n.oid = c.relnamespace
+        join pg_catalog.pg_type t on t.oid = a.atttypid
+        left join pg_catalog.pg_type et
+            on et.oid = t.typelem
+            and t.typcategory = 'A'
+        where c.relkind in ('r', 'p', 'v', 'm', 'f')
+            and a.attnum > 0
+            and not a.attisdropped
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_domains(pool: &sqlx::postgres::PgPool) -> anyhow::Result<Vec<schema::Domain>> {
+    let rows: Vec<schema::Domain> = sqlx::query_as(
+        r#"
+        select
+            d.*,
+            t.typnotnull as is_not_null,
+            case when bt.typcategory = 'A' then
+                pg_catalog.format_type(t.typbasetype, t.typtypmod)
+            end as array_data_type
+        from information_schema.domains d
+        join pg_catalog.pg_namespace n on n.nspname = d.domain_schema
+        join pg_catalog.pg_type t on t.typnamespace = n.oid and t.typname = d.domain_name
+        join pg_catalog.pg_type bt on bt.oid = t.typbasetype
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_domain_constraints(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::DomainConstraint>> {
+    let rows: Vec<schema::DomainConstraint> =
+        sqlx::query_as(r#"select * from information_schema.domain_constraints"#)
+            .fetch_all(pool)
+            .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_sequences(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::Sequence>> {
+    // an owning column is recorded as an 'a' (auto) dependency, or an 'i'
+    // (internal) one for identity columns. regclass::text is quoted and
+    // qualified the same way pg_get_expr prints the nextval() argument.
+    let rows: Vec<schema::Sequence> = sqlx::query_as(
+        r#"
+        select
+            n.nspname as sequence_schema,
+            c.relname as sequence_name,
+            pg_catalog.format_type(s.seqtypid, null) as data_type,
+            s.seqstart as start_value,
+            s.seqincrement as increment,
+            s.seqmin as minimum_value,
+            s.seqmax as maximum_value,
+            s.seqcache as cache_size,
+            s.seqcycle as cycle_option,
+            tn.nspname as owned_by_table_schema,
+            tc.relname as owned_by_table_name,
+            a.attname as owned_by_column_name,
+            coalesce(d.deptype = 'i', false) as is_identity,
+            coalesce(
+                pg_catalog.pg_get_expr(ad.adbin, ad.adrelid)
+                    = format('nextval(%L::regclass)', c.oid::regclass::text),
+                false
+            ) as is_owner_default
+        from pg_catalog.pg_sequence s
+        join pg_catalog.pg_class c on c.oid = s.seqrelid
+        join pg_catalog.pg_namespace n on n.oid = c.relnamespace
+        left join pg_catalog.pg_depend d
+            on d.classid = 'pg_catalog.pg_class'::regclass
+            and d.objid = c.oid
+            and d.refclassid = 'pg_catalog.pg_class'::regclass
+            and d.refobjsubid > 0
+            and d.deptype in ('a', 'i')
+        left join pg_catalog.pg_class tc on tc.oid = d.refobjid
+        left join pg_catalog.pg_namespace tn on tn.oid = tc.relnamespace
+        left join pg_catalog.pg_attribute a
+            on a.attrelid = d.refobjid
+            and a.attnum = d.refobjsubid
+        left join pg_catalog.pg_attrdef ad
+            on ad.adrelid = d.refobjid
+            and ad.adnum = d.refobjsubid
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_constraint_details(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::ConstraintDetail>> {
+    let rows: Vec<schema::ConstraintDetail> = sqlx::query_as(
+        r#"
+        select
+            n.nspname as constraint_schema,
+            con.conname as constraint_name,
+            tn.nspname as table_schema,
+            tc.relname as table_name,
+            con.contype::text as constraint_type,
+            pg_catalog.pg_get_expr(con.conbin, con.conrelid, true) as check_expression,
+            con.connoinherit as is_no_inherit,
+            con.convalidated as is_validated,
+            (
+                select a.attname::text
+                from pg_catalog.pg_attribute a
+                where con.contype = 'c'
+                    and cardinality(con.conkey) = 1
+                    and a.attrelid = con.conrelid
+                    and a.attnum = con.conkey[1]
+            ) as check_column,
+            (
+                select array_agg(a.attname::text order by k.n)
+                from unnest(con.confkey) with ordinality k(attnum, n)
+                join pg_catalog.pg_attribute a
+                    on a.attrelid = con.confrelid and a.attnum = k.attnum
+            ) as referenced_columns,
+            -- confdelsetcols only exists from postgres v15 on
+            (
+                select array_agg(a.attname::text order by k.n)
+                from jsonb_array_elements_text(
+                    nullif(to_jsonb(con) -> 'confdelsetcols', 'null')
+                ) with ordinality k(attnum, n)
+                join pg_catalog.pg_attribute a
+                    on a.attrelid = con.conrelid and a.attnum = k.attnum::int2
+            ) as delete_set_columns,
+            (
+                select array_agg(format('%s.%s', opn.nspname, o.oprname) order by k.n)
+                from unnest(con.conexclop) with ordinality k(opr, n)
+                join pg_catalog.pg_operator o on o.oid = k.opr
+                join pg_catalog.pg_namespace opn on opn.oid = o.oprnamespace
+            ) as exclusion_operators,
+            con.condeferrable as is_deferrable,
+            con.condeferred as is_initially_deferred,
+            con.conislocal as is_local,
+            con.coninhcount as inherited_count
+        from pg_catalog.pg_constraint con
+        join pg_catalog.pg_namespace n on n.oid = con.connamespace
+        join pg_catalog.pg_class tc on tc.oid = con.conrelid
+        join pg_catalog.pg_namespace tn on tn.oid = tc.relnamespace
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_partitioned_tables(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::PartitionedTable>> {
+    let rows: Vec<schema::PartitionedTable> = sqlx::query_as(
+        r#"
+        select
+            n.nspname as table_schema,
+            c.relname as table_name,
+            pg_catalog.pg_get_partkeydef(c.oid) as partition_key
+        from pg_catalog.pg_partitioned_table p
+        join pg_catalog.pg_class c on c.oid = p.partrelid
+        join pg_catalog.pg_namespace n on n.oid = c.relnamespace
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_table_inheritance(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::TableInheritance>> {
+    // indexes of partitioned tables are in pg_inherits as well, hence the
+    // relkind filter
+    let rows: Vec<schema::TableInheritance> = sqlx::query_as(
+        r#"
+        select
+            n.nspname as table_schema,
+            c.relname as table_name,
+            pn.nspname as parent_schema,
+            p.relname as parent_name,
+            i.inhseqno as sequence_number,
+            case when c.relispartition
+                then pg_catalog.pg_get_expr(c.relpartbound, c.oid)
+            end as partition_bound
+        from pg_catalog.pg_inherits i
+        join pg_catalog.pg_class c on c.oid = i.inhrelid
+        join pg_catalog.pg_namespace n on n.oid = c.relnamespace
+        join pg_catalog.pg_class p on p.oid = i.inhparent
+        join pg_catalog.pg_namespace pn on pn.oid = p.relnamespace
+        where c.relkind in ('r', 'p', 'f')
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_indexes(pool: &sqlx::postgres::PgPool) -> anyhow::Result<Vec<schema::Index>> {
+    let rows: Vec<schema::Index> = sqlx::query_as(
+        r#"
+        select
+            n.nspname as index_schema,
+            c.relname as index_name,
+            tn.nspname as table_schema,
+            tc.relname as table_name,
+            am.amname as index_method,
+            i.indisunique as is_unique,
+            i.indisprimary as is_primary,
+            pg_catalog.pg_get_expr(i.indpred, i.indrelid, true) as predicate,
+            con.conname as constraint_name,
+            c.relispartition as is_partition
+        from pg_catalog.pg_index i
+        join pg_catalog.pg_class c on c.oid = i.indexrelid
+        join pg_catalog.pg_namespace n on n.oid = c.relnamespace
+        join pg_catalog.pg_class tc on tc.oid = i.indrelid
+        join pg_catalog.pg_namespace tn on tn.oid = tc.relnamespace
+        join pg_catalog.pg_am am on am.oid = c.relam
+        left join pg_catalog.pg_constraint con
+            on con.conindid = i.indexrelid
+            and con.conrelid = i.indrelid
+            and con.contype in ('p', 'u', 'x')
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_index_columns(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::IndexColumn>> {
+    // indkey, indclass, indcollation and indoption are zero-based vectors.
+    // the last three only have entries for key columns, so they come back
+    // null for INCLUDE columns.
+    let rows: Vec<schema::IndexColumn> = sqlx::query_as(
+        r#"
+        select
+            n.nspname as index_schema,
+            c.relname as index_name,
+            k.ordinal_position,
+            k.ordinal_position > i.indnkeyatts as is_included,
+            a.attname as column_name,
+            pg_catalog.pg_get_indexdef(i.indexrelid, k.ordinal_position, true) as definition,
+            case when opc.opcdefault then null else opcn.nspname end as opclass_schema,
+            case when opc.opcdefault then null else opc.opcname end as opclass_name,
+            case
+                when coll.oid = coalesce(a.attcollation, t.typcollation) then null
+                else colln.nspname
+            end as collation_schema,
+            case
+                when coll.oid = coalesce(a.attcollation, t.typcollation) then null
+                else coll.collname
+            end as collation_name,
+            coalesce(i.indoption[k.ordinal_position - 1] & 1 = 1, false) as is_descending,
+            coalesce(i.indoption[k.ordinal_position - 1] & 2 = 2, false) as is_nulls_first
+        from pg_catalog.pg_index i
+        join pg_catalog.pg_class c on c.oid = i.indexrelid
+        join pg_catalog.pg_namespace n on n.oid = c.relnamespace
+        cross join lateral generate_series(1, i.indnatts::int) as k(ordinal_position)
+        join pg_catalog.pg_attribute ia
+            on ia.attrelid = i.indexrelid
+            and ia.attnum = k.ordinal_position
+        join pg_catalog.pg_type t on t.oid = ia.atttypid
+        left join pg_catalog.pg_attribute a
+            on a.attrelid = i.indrelid
+            and a.attnum = i.indkey[k.ordinal_position - 1]
+            and a.attnum > 0
+        left join pg_catalog.pg_opclass opc on opc.oid = i.indclass[k.ordinal_position - 1]
+        left join pg_catalog.pg_namespace opcn on opcn.oid = opc.opcnamespace
+        left join pg_catalog.pg_collation coll on coll.oid = i.indcollation[k.ordinal_position - 1]
+        left join pg_catalog.pg_namespace colln on colln.oid = coll.collnamespace
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_collations(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::Collation>> {
+    // the icu locale column is colliculocale on postgres 15 and 16 but
+    // colllocale from 17 on, going through jsonb reads either one.
+    let rows: Vec<schema::Collation> = sqlx::query_as(
+        r#"
+        select
+            n.nspname as collation_schema,
+            c.collname as collation_name,
+            case c.collprovider
+                when 'i' then 'icu'
+                when 'c' then 'libc'
+                else 'default'
+            end as provider,
+            case when c.collprovider = 'c' then c.collcollate end as lc_collate,
+            case when c.collprovider = 'c' then c.collctype end as lc_ctype,
+            case when c.collprovider = 'i' then coalesce(
+                to_jsonb(c) ->> 'colllocale',
+                to_jsonb(c) ->> 'colliculocale'
+            ) end as icu_locale,
+            c.collisdeterministic as is_deterministic
+        from pg_catalog.pg_collation c
+        join pg_catalog.pg_namespace n on n.oid = c.collnamespace
+        where not exists (
+            select
+            from pg_catalog.pg_depend d
+            where d.classid = 'pg_catalog.pg_collation'::regclass
+                and d.objid = c.oid
+                and d.deptype = 'e'
+        )
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_foreign_data_wrappers(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::ForeignDataWrapper>> {
+    let rows: Vec<schema::ForeignDataWrapper> = sqlx::query_as(
+        r#"
+        select
+            w.fdwname as foreign_data_wrapper_name,
+            nullif(w.fdwhandler, 0)::regproc::text as handler,
+            nullif(w.fdwvalidator, 0)::regproc::text as validator
+        from pg_catalog.pg_foreign_data_wrapper w
+        where not exists (
+            select
+            from pg_catalog.pg_depend d
+            where d.classid = 'pg_catalog.pg_foreign_data_wrapper'::regclass
+                and d.objid = w.oid
+                and d.deptype = 'e'
+        )
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_foreign_data_wrapper_options(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::ForeignDataWrapperOption>> {
+    let rows: Vec<schema::ForeignDataWrapperOption> =
+        sqlx::query_as(r#"select * from information_schema.foreign_data_wrapper_options"#)
+            .fetch_all(pool)
+            .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_foreign_servers(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::ForeignServer>> {
+    let rows: Vec<schema::ForeignServer> =
+        sqlx::query_as(r#"select * from information_schema.foreign_servers"#)
+            .fetch_all(pool)
+            .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_foreign_server_options(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::ForeignServerOption>> {
+    let rows: Vec<schema::ForeignServerOption> =
+        sqlx::query_as(r#"select * from information_schema.foreign_server_options"#)
+            .fetch_all(pool)
+            .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_foreign_tables(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::ForeignTable>> {
+    let rows: Vec<schema::ForeignTable> =
+        sqlx::query_as(r#"select * from information_schema.foreign_tables"#)
+            .fetch_all(pool)
+            .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_foreign_table_options(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::ForeignTableOption>> {
+    let rows: Vec<schema::ForeignTableOption> =
+        sqlx::query_as(r#"select * from information_schema.foreign_table_options"#)
+            .fetch_all(pool)
+            .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_column_options(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::ColumnOption>> {
+    let rows: Vec<schema::ColumnOption> =
+        sqlx::query_as(r#"select * from information_schema.column_options"#)
+            .fetch_all(pool)
+            .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_user_mappings(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::UserMapping>> {
+    let rows: Vec<schema::UserMapping> =
+        sqlx::query_as(r#"select * from information_schema.user_mappings"#)
+            .fetch_all(pool)
+            .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_user_mapping_options(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::UserMappingOption>> {
+    let rows: Vec<schema::UserMappingOption> =
+        sqlx::query_as(r#"select * from information_schema.user_mapping_options"#)
+            .fetch_all(pool)
+            .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_materialized_views(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::MaterializedView>> {
+    let rows: Vec<schema::MaterializedView> =
+        sqlx::query_as(r#"select * from pg_catalog.pg_matviews"#)
+            .fetch_all(pool)
+            .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_materialized_view_usage(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::MaterializedViewUsage>> {
+    // a view's query lives in its pg_rewrite rule, which is what depends on
+    // the relations the query reads
+    let rows: Vec<schema::MaterializedViewUsage> = sqlx::query_as(
+        r#"
+        select distinct
+            vn.nspname as view_schema,
+            v.relname as view_name,
+            tn.nspname as table_schema,
+            t.relname as table_name
+        from pg_catalog.pg_rewrite r
+        join pg_catalog.pg_class v on v.oid = r.ev_class
+        join pg_catalog.pg_namespace vn on vn.oid = v.relnamespace
+        join pg_catalog.pg_depend d
+            on d.classid = 'pg_catalog.pg_rewrite'::regclass
+            and d.objid = r.oid
+            and d.refclassid = 'pg_catalog.pg_class'::regclass
+        join pg_catalog.pg_class t on t.oid = d.refobjid
+        join pg_catalog.pg_namespace tn on tn.oid = t.relnamespace
+        where t.oid <> v.oid
+            and (v.relkind = 'm' or (v.relkind = 'v' and t.relkind = 'm'))
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_routines(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::Routine>> {
+    // pg_get_functiondef refuses aggregates, those are read separately
+    let rows: Vec<schema::Routine> = sqlx::query_as(
+        r#"
+        select
+            n.nspname as routine_schema,
+            p.proname as routine_name,
+            p.prokind::text as kind,
+            pg_catalog.pg_get_function_arguments(p.oid) as arguments,
+            pg_catalog.pg_get_function_identity_arguments(p.oid) as identity_arguments,
+            pg_catalog.pg_get_function_result(p.oid) as result,
+            l.lanname as language,
+            pg_catalog.pg_get_functiondef(p.oid) as definition
+        from pg_catalog.pg_proc p
+        join pg_catalog.pg_namespace n on n.oid = p.pronamespace
+        join pg_catalog.pg_language l on l.oid = p.prolang
+        where p.prokind in ('f', 'p', 'w')
+            and not exists (
+                select
+                from pg_catalog.pg_depend d
+                where d.classid = 'pg_catalog.pg_proc'::regclass
+                    and d.objid = p.oid
+                    and d.deptype = 'e'
+            )
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_routine_type_usage(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::RoutineTypeUsage>> {
+    // an argument of type people[] depends on the array type, but it is
+    // people that has to exist first
+    let rows: Vec<schema::RoutineTypeUsage> = sqlx::query_as(
+        r#"
+        select distinct
+            n.nspname as routine_schema,
+            p.proname as routine_name,
+            pg_catalog.pg_get_function_identity_arguments(p.oid) as identity_arguments,
+            tn.nspname as type_schema,
+            t.typname as type_name,
+            coalesce(c.relkind <> 'c', false) as is_relation_row_type
+        from pg_catalog.pg_depend d
+        join pg_catalog.pg_proc p on p.oid = d.objid
+        join pg_catalog.pg_namespace n on n.oid = p.pronamespace
+        join pg_catalog.pg_type at on at.oid = d.refobjid
+        join pg_catalog.pg_type t
+            on t.oid = case
+                when at.typcategory = 'A' and at.typelem <> 0 then at.typelem
+                else at.oid
+            end
+        join pg_catalog.pg_namespace tn on tn.oid = t.typnamespace
+        left join pg_catalog.pg_class c on c.oid = t.typrelid
+        where d.classid = 'pg_catalog.pg_proc'::regclass
+            and d.refclassid = 'pg_catalog.pg_type'::regclass
+            and d.deptype = 'n'
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_domain_routine_usage(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::DomainRoutineUsage>> {
+    // a domain's default depends on the routines it calls directly, its check
+    // constraints through their pg_constraint row
+    let rows: Vec<schema::DomainRoutineUsage> = sqlx::query_as(
+        r#"
+        select distinct
+            tn.nspname as domain_schema,
+            t.typname as domain_name,
+            n.nspname as routine_schema,
+            p.proname as routine_name,
+            pg_catalog.pg_get_function_identity_arguments(p.oid) as identity_arguments
+        from pg_catalog.pg_depend d
+        left join pg_catalog.pg_constraint con
+            on d.classid = 'pg_catalog.pg_constraint'::regclass
+            and con.oid = d.objid
+        join pg_catalog.pg_type t
+            on t.oid = case
+                when d.classid = 'pg_catalog.pg_type'::regclass then d.objid
+                else con.contypid
+            end
+        join pg_catalog.pg_namespace tn on tn.oid = t.typnamespace
+        join pg_catalog.pg_proc p on p.oid = d.refobjid
+        join pg_catalog.pg_namespace n on n.oid = p.pronamespace
+        where d.classid in ('pg_catalog.pg_type'::regclass, 'pg_catalog.pg_constraint'::regclass)
+            and d.refclassid = 'pg_catalog.pg_proc'::regclass
+            and t.typtype = 'd'
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_aggregates(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::Aggregate>> {
+    // support functions print schema qualified unless they are on the search path
+    let rows: Vec<schema::Aggregate> = sqlx::query_as(
+        r#"
+        select
+            n.nspname as aggregate_schema,
+            p.proname as aggregate_name,
+            a.aggkind::text as kind,
+            pg_catalog.pg_get_function_arguments(p.oid) as arguments,
+            pg_catalog.pg_get_function_identity_arguments(p.oid) as identity_arguments,
+            a.aggtransfn::text as transition_function,
+            pg_catalog.format_type(a.aggtranstype, null) as transition_type,
+            a.aggtransspace as transition_space,
+            nullif(a.aggfinalfn::oid, 0)::regproc::text as final_function,
+            a.aggfinalextra as final_function_extra,
+            a.aggfinalmodify::text as final_function_modify,
+            nullif(a.aggcombinefn::oid, 0)::regproc::text as combine_function,
+            nullif(a.aggserialfn::oid, 0)::regproc::text as serial_function,
+            nullif(a.aggdeserialfn::oid, 0)::regproc::text as deserial_function,
+            a.agginitval as initial_condition,
+            nullif(a.aggmtransfn::oid, 0)::regproc::text as moving_transition_function,
+            nullif(a.aggminvtransfn::oid, 0)::regproc::text as moving_inverse_function,
+            case when a.aggmtranstype <> 0
+                then pg_catalog.format_type(a.aggmtranstype, null)
+            end as moving_transition_type,
+            a.aggmtransspace as moving_transition_space,
+            nullif(a.aggmfinalfn::oid, 0)::regproc::text as moving_final_function,
+            a.aggmfinalextra as moving_final_function_extra,
+            a.aggmfinalmodify::text as moving_final_function_modify,
+            a.aggminitval as moving_initial_condition,
+            case when a.aggsortop <> 0
+                then format('%s.%s', opn.nspname, op.oprname)
+            end as sort_operator,
+            p.proparallel::text as parallel
+        from pg_catalog.pg_aggregate a
+        join pg_catalog.pg_proc p on p.oid = a.aggfnoid
+        join pg_catalog.pg_namespace n on n.oid = p.pronamespace
+        left join pg_catalog.pg_operator op on op.oid = a.aggsortop
+        left join pg_catalog.pg_namespace opn on opn.oid = op.oprnamespace
+        where not exists (
+            select
+            from pg_catalog.pg_depend d
+            where d.classid = 'pg_catalog.pg_proc'::regclass
+                and d.objid = p.oid
+                and d.deptype = 'e'
+        )
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_schemas(pool: &sqlx::postgres::PgPool) -> anyhow::Result<Vec<schema::Schema>> {
+    // information_schema.schemata only lists the schemas the current user owns
+    let rows: Vec<schema::Schema> = sqlx::query_as(
+        r#"
+        select
+            n.nspname as schema_name,
+            pg_catalog.pg_get_userbyid(n.nspowner) as schema_owner
+        from pg_catalog.pg_namespace n
+        where not exists (
+            select
+            from pg_catalog.pg_depend d
+            where d.classid = 'pg_catalog.pg_namespace'::regclass
+                and d.objid = n.oid
+                and d.deptype = 'e'
+        )
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_extensions(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::Extension>> {
+    let rows: Vec<schema::Extension> = sqlx::query_as(
+        r#"
+        select
+            e.extname as extension_name,
+            n.nspname as extension_schema,
+            e.extversion as extension_version,
+            array(
+                select r.extname::text
+                from pg_catalog.pg_depend d
+                join pg_catalog.pg_extension r on r.oid = d.refobjid
+                where d.classid = 'pg_catalog.pg_extension'::regclass
+                    and d.objid = e.oid
+                    and d.refclassid = 'pg_catalog.pg_extension'::regclass
+                order by r.extname
+            ) as requires
+        from pg_catalog.pg_extension e
+        join pg_catalog.pg_namespace n on n.oid = e.extnamespace
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_extension_members(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::ExtensionMember>> {
+    let rows: Vec<schema::ExtensionMember> = sqlx::query_as(
+        r#"
+        select
+            e.extname as extension_name,
+            n.nspname as object_schema,
+            c.relname as object_name,
+            'relation' as object_type
+        from pg_catalog.pg_depend d
+        join pg_catalog.pg_extension e on e.oid = d.refobjid
+        join pg_catalog.pg_class c on c.oid = d.objid
+        join pg_catalog.pg_namespace n on n.oid = c.relnamespace
+        where d.classid = 'pg_catalog.pg_class'::regclass
+            and d.refclassid = 'pg_catalog.pg_extension'::regclass
+            and d.deptype = 'e'
+        union all
+        select
+            e.extname as extension_name,
+            n.nspname as object_schema,
+            t.typname as object_name,
+            'type' as object_type
+        from pg_catalog.pg_depend d
+        join pg_catalog.pg_extension e on e.oid = d.refobjid
+        join pg_catalog.pg_type t on t.oid = d.objid
+        join pg_catalog.pg_namespace n on n.oid = t.typnamespace
+        where d.classid = 'pg_catalog.pg_type'::regclass
+            and d.refclassid = 'pg_catalog.pg_extension'::regclass
+            and d.deptype = 'e'
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_privileges(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::Privilege>> {
+    // a null acl means the object still has its starting privileges. otherwise
+    // the acl is compared with those, leaving out who granted what.
+    let rows: Vec<schema::Privilege> = sqlx::query_as(
+        r#"
+        with objects as (
+            select
+                case when c.relkind = 'S' then 'SEQUENCE' else 'TABLE' end as object_type,
+                n.nspname as object_schema,
+                c.relname as object_name,
+                null::text as column_name,
+                null::text as identity_arguments,
+                c.relacl as acl,
+                coalesce(
+                    ip.initprivs,
+                    pg_catalog.acldefault(case when c.relkind = 'S' then 's' else 'r' end::"char", c.relowner)
+                ) as default_acl,
+                'pg_catalog.pg_class'::regclass as classid,
+                c.oid as objid
+            from pg_catalog.pg_class c
+            join pg_catalog.pg_namespace n on n.oid = c.relnamespace
+            left join pg_catalog.pg_init_privs ip
+                on ip.classoid = 'pg_catalog.pg_class'::regclass
+                and ip.objoid = c.oid
+                and ip.objsubid = 0
+            where c.relkind in ('r', 'p', 'v', 'm', 'f', 'S')
+            union all
+            select
+                'COLUMN',
+                n.nspname,
+                c.relname,
+                a.attname::text,
+                null,
+                a.attacl,
+                coalesce(ip.initprivs, pg_catalog.acldefault('c', c.relowner)),
+                'pg_catalog.pg_class'::regclass,
+                c.oid
+            from pg_catalog.pg_attribute a
+            join pg_catalog.pg_class c on c.oid = a.attrelid
+            join pg_catalog.pg_namespace n on n.oid = c.relnamespace
+            left join pg_catalog.pg_init_privs ip
+                on ip.classoid = 'pg_catalog.pg_class'::regclass
+                and ip.objoid = c.oid
+                and ip.objsubid = a.attnum
+            where c.relkind in ('r', 'p', 'v', 'm', 'f')
+                and a.attnum > 0
+                and not a.attisdropped
+            union all
+            select
+                'SCHEMA',
+                n.nspname,
+                n.nspname,
+                null,
+                null,
+                n.nspacl,
+                coalesce(ip.initprivs, pg_catalog.acldefault('n', n.nspowner)),
+                'pg_catalog.pg_namespace'::regclass,
+                n.oid
+            from pg_catalog.pg_namespace n
+            left join pg_catalog.pg_init_privs ip
+                on ip.classoid = 'pg_catalog.pg_namespace'::regclass
+                and ip.objoid = n.oid
+                and ip.objsubid = 0
+            union all
+            select
+                case when p.prokind = 'p' then 'PROCEDURE' else 'FUNCTION' end,
+                n.nspname,
+                p.proname,
+                null,
+                pg_catalog.pg_get_function_identity_arguments(p.oid),
+                p.proacl,
+                coalesce(ip.initprivs, pg_catalog.acldefault('f', p.proowner)),
+                'pg_catalog.pg_proc'::regclass,
+                p.oid
+            from pg_catalog.pg_proc p
+            join pg_catalog.pg_namespace n on n.oid = p.pronamespace
+            left join pg_catalog.pg_init_privs ip
+                on ip.classoid = 'pg_catalog.pg_proc'::regclass
+                and ip.objoid = p.oid
+                and ip.objsubid = 0
+        ),
+        changes as (
+            select o.*, a.grantee, a.privilege_type, a.is_grantable, true as is_grant
+            from objects o
+            cross join lateral (
+                select grantee, privilege_type, is_grantable
+                from pg_catalog.aclexplode(o.acl)
+                except
+                select grantee, privilege_type, is_grantable
+                from pg_catalog.aclexplode(o.default_acl)
+            ) a
+            where o.acl is not null
+            union all
+            select o.*, a.grantee, a.privilege_type, false, false
+            from objects o
+            cross join lateral (
+                select grantee, privilege_type
+                from pg_catalog.aclexplode(o.default_acl)
+                except
+                select grantee, privilege_type
+                from pg_catalog.aclexplode(o.acl)
+            ) a
+            where o.acl is not null
+        )
+        select
+            c.object_type,
+            c.object_schema,
+            c.object_name,
+            c.column_name,
+            c.identity_arguments,
+            case when c.grantee <> 0 then pg_catalog.pg_get_userbyid(c.grantee) end as grantee,
+            c.privilege_type,
+            c.is_grantable,
+            c.is_grant
+        from changes c
+        where not exists (
+            select
+            from pg_catalog.pg_depend d
+            where d.classid = c.classid
+                and d.objid = c.objid
+                and d.deptype = 'e'
+        )
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_default_privileges(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::DefaultPrivilege>> {
+    // default privileges for a single schema are added to the global ones,
+    // so only the global ones are compared with the built-in defaults
+    let rows: Vec<schema::DefaultPrivilege> = sqlx::query_as(
+        r#"
+        with defaults as (
+            select
+                d.defaclrole,
+                n.nspname,
+                d.defaclobjtype,
+                d.defaclacl as acl,
+                case
+                    when d.defaclnamespace <> 0 then null
+                    when d.defaclobjtype = 'S' then pg_catalog.acldefault('s', d.defaclrole)
+                    else pg_catalog.acldefault(d.defaclobjtype, d.defaclrole)
+                end as default_acl
+            from pg_catalog.pg_default_acl d
+            left join pg_catalog.pg_namespace n on n.oid = d.defaclnamespace
+        ),
+        changes as (
+            select d.*, a.grantee, a.privilege_type, a.is_grantable, true as is_grant
+            from defaults d
+            cross join lateral (
+                select grantee, privilege_type, is_grantable
+                from pg_catalog.aclexplode(d.acl)
+                except
+                select grantee, privilege_type, is_grantable
+                from pg_catalog.aclexplode(d.default_acl)
+            ) a
+            union all
+            select d.*, a.grantee, a.privilege_type, false, false
+            from defaults d
+            cross join lateral (
+                select grantee, privilege_type
+                from pg_catalog.aclexplode(d.default_acl)
+                except
+                select grantee, privilege_type
+                from pg_catalog.aclexplode(d.acl)
+            ) a
+        )
+        select
+            pg_catalog.pg_get_userbyid(c.defaclrole) as role_name,
+            c.nspname as schema_name,
+            case c.defaclobjtype
+                when 'r' then 'TABLES'
+                when 'S' then 'SEQUENCES'
+                when 'f' then 'FUNCTIONS'
+                when 'T' then 'TYPES'
+                when 'n' then 'SCHEMAS'
+            end as object_type,
+            case when c.grantee <> 0 then pg_catalog.pg_get_userbyid(c.grantee) end as grantee,
+            c.privilege_type,
+            c.is_grantable,
+            c.is_grant
+        from changes c
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_row_security(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::RowSecurity>> {
+    let rows: Vec<schema::RowSecurity> = sqlx::query_as(
+        r#"
+        select
+            n.nspname as table_schema,
+            c.relname as table_name,
+            c.relrowsecurity as is_enabled,
+            c.relforcerowsecurity as is_forced
+        from pg_catalog.pg_class c
+        join pg_catalog.pg_namespace n on n.oid = c.relnamespace
+        where c.relrowsecurity or c.relforcerowsecurity
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_policies(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::Policy>> {
+    let rows: Vec<schema::Policy> = sqlx::query_as(r#"select * from pg_catalog.pg_policies"#)
+        .fetch_all(pool)
+        .await?;
+
+    Ok(rows)
+}
+
+pub async fn get_all_comments(
+    pool: &sqlx::postgres::PgPool,
+) -> anyhow::Result<Vec<schema::Comment>> {
+    let rows: Vec<schema::Comment> = sqlx::query_as(
+        r#"
+        select
+            case when d.objsubid = 0 then 'relation' else 'column' end as object_type,
+            n.nspname as object_schema,
+            c.relname as object_name,
+            a.attname::text as member_name,
+            null as identity_arguments,
+            d.description
+        from pg_catalog.pg_description d
+        join pg_catalog.pg_class c on c.oid = d.objoid
+        join pg_catalog.pg_namespace n on n.oid = c.relnamespace
+        left join pg_catalog.pg_attribute a
+            on a.attrelid = c.oid
+            and a.attnum = d.objsubid
+            and d.objsubid <> 0
+        where d.classoid = 'pg_catalog.pg_class'::regclass
+        union all
+        select
+            case when con.contypid <> 0 then 'domain constraint' else 'constraint' end,
+            n.nspname,
+            coalesce(c.relname, t.typname),
+            con.conname::text,
+            null,
+            d.description
+        from pg_catalog.pg_description d
+        join pg_catalog.pg_constraint con on con.oid = d.objoid
+        join pg_catalog.pg_namespace n on n.oid = con.connamespace
+        left join pg_catalog.pg_class c on c.oid = con.conrelid
+        left join pg_catalog.pg_type t on t.oid = con.contypid
+        where d.classoid = 'pg_catalog.pg_constraint'::regclass
+        union all
+        select
+            'type',
+            n.nspname,
+            t.typname,
+            null,
+            null,
+            d.description
+        from pg_catalog.pg_description d
+        join pg_catalog.pg_type t on t.oid = d.objoid
+        join pg_catalog.pg_namespace n on n.oid = t.typnamespace
+        where d.classoid = 'pg_catalog.pg_type'::regclass
+        union all
+        select
+            'function',
+            n.nspname,
+            p.proname,
+            null,
+            pg_catalog.pg_get_function_identity_arguments(p.oid),
+            d.description
+        from pg_catalog.pg_description d
+        join pg_catalog.pg_proc p on p.oid = d.objoid
+        join pg_catalog.pg_namespace n on n.oid = p.pronamespace
+        where d.classoid = 'pg_catalog.pg_proc'::regclass
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
 pub async fn get_all(pool: &sqlx::postgres::PgPool) -> anyhow::Result<schema::All> {
     let mut fetch_start_time = None;
     if log::log_enabled!(log::Level::Info) {
@@ -99,22 +1301,102 @@ pub async fn get_all(pool: &sqlx::postgres::PgPool) -> anyhow::Result<schema::Al
         tables_res,
         columns_res,
         views_res,
+        view_table_usage_res,
+        triggers_res,
         table_constraints_res,
         constraint_column_usage_res,
         key_column_usage_res,
         constraint_table_usage_res,
+        referential_constraints_res,
         element_types_res,
         check_constraints_res,
+        enums_res,
+        enum_labels_res,
+        composite_types_res,
+        composite_type_attributes_res,
+        domains_res,
+        domain_constraints_res,
+        column_attributes_res,
+        sequences_res,
+        constraint_details_res,
+        partitioned_tables_res,
+        table_inheritance_res,
+        foreign_data_wrappers_res,
+        foreign_data_wrapper_options_res,
+        foreign_servers_res,
+        foreign_server_options_res,
+        foreign_tables_res,
+        foreign_table_options_res,
+        column_options_res,
+        user_mappings_res,
+        user_mapping_options_res,
+        collations_res,
+        materialized_views_res,
+        materialized_view_usage_res,
+        routines_res,
+        routine_type_usage_res,
+        domain_routine_usage_res,
+        aggregates_res,
+        extensions_res,
+        extension_members_res,
+        schemas_res,
+        privileges_res,
+        default_privileges_res,
+        row_security_res,
+        policies_res,
+        comments_res,
+        indexes_res,
+        index_columns_res,
     ) = futures::join!(
-        get_all_tables(&pool),
-        get_all_columns(&pool),
-        get_all_views(&pool),
-        get_all_table_constraints(&pool),
-        get_all_constraint_column_usage(&pool),
-        get_all_key_column_usage(&pool),
-        get_all_constraint_table_usage(&pool),
-        get_all_element_types(&pool),
-        get_all_check_constraints(&pool),
+        get_all_tables(pool),
+        get_all_columns(pool),
+        get_all_views(pool),
+        get_all_view_table_usage(pool),
+        get_all_triggers(pool),
+        get_all_table_constraints(pool),
+        get_all_constraint_column_usage(pool),
+        get_all_key_column_usage(pool),
+        get_all_constraint_table_usage(pool),
+        get_all_referential_constraints(pool),
+        get_all_element_types(pool),
+        get_all_check_constraints(pool),
+        get_all_enums(pool),
+        get_all_enum_labels(pool),
+        get_all_composite_types(pool),
+        get_all_composite_type_attributes(pool),
+        get_all_domains(pool),
+        get_all_domain_constraints(pool),
+        get_all_column_attributes(pool),
+        get_all_sequences(pool),
+        get_all_constraint_details(pool),
+        get_all_partitioned_tables(pool),
+        get_all_table_inheritance(pool),
+        get_all_foreign_data_wrappers(pool),
+        get_all_foreign_data_wrapper_options(pool),
+        get_all_foreign_servers(pool),
+        get_all_foreign_server_options(pool),
+        get_all_foreign_tables(pool),
+        get_all_foreign_table_options(pool),
+        get_all_column_options(pool),
+        get_all_user_mappings(pool),
+        get_all_user_mapping_options(pool),
+        get_all_collations(pool),
+        get_all_materialized_views(pool),
+        get_all_materialized_view_usage(pool),
+        get_all_routines(pool),
+        get_all_routine_type_usage(pool),
+        get_all_domain_routine_usage(pool),
+        get_all_aggregates(pool),
+        get_all_extensions(pool),
+        get_all_extension_members(pool),
+        get_all_schemas(pool),
+        get_all_privileges(pool),
+        get_all_default_privileges(pool),
+        get_all_row_security(pool),
+        get_all_policies(pool),
+        get_all_comments(pool),
+        get_all_indexes(pool),
+        get_all_index_columns(pool),
     );
     if let Some(dur) = fetch_start_time.and_then(|s| s.elapsed().ok()) {
         log::info!(
@@ -127,34 +1409,154 @@ pub async fn get_all(pool: &sqlx::postgres::PgPool) -> anyhow::Result<schema::Al
         tables,
         columns,
         views,
+        view_table_usage,
+        triggers,
         table_constraints,
         constraint_column_usage,
         key_column_usage,
         constraint_table_usage,
+        referential_constraints,
         element_types,
         check_constraints,
+        enums,
+        enum_labels,
+        composite_types,
+        composite_type_attributes,
+        domains,
+        domain_constraints,
+        column_attributes,
+        sequences,
+        constraint_details,
+        partitioned_tables,
+        table_inheritance,
+        foreign_data_wrappers,
+        foreign_data_wrapper_options,
+        foreign_servers,
+        foreign_server_options,
+        foreign_tables,
+        foreign_table_options,
+        column_options,
+        user_mappings,
+        user_mapping_options,
+        collations,
+        materialized_views,
+        materialized_view_usage,
+        routines,
+        routine_type_usage,
+        domain_routine_usage,
+        aggregates,
+        extensions,
+        extension_members,
+        schemas,
+        privileges,
+        default_privileges,
+        row_security,
+        policies,
+        comments,
+        indexes,
+        index_columns,
     ) = (
         tables_res?,
         columns_res?,
         views_res?,
+        view_table_usage_res?,
+        triggers_res?,
         table_constraints_res?,
         constraint_column_usage_res?,
         key_column_usage_res?,
         constraint_table_usage_res?,
+        referential_constraints_res?,
         element_types_res?,
         check_constraints_res?,
+        enums_res?,
+        enum_labels_res?,
+        composite_types_res?,
+        composite_type_attributes_res?,
+        domains_res?,
+        domain_constraints_res?,
+        column_attributes_res?,
+        sequences_res?,
+        constraint_details_res?,
+        partitioned_tables_res?,
+        table_inheritance_res?,
+        foreign_data_wrappers_res?,
+        foreign_data_wrapper_options_res?,
+        foreign_servers_res?,
+        foreign_server_options_res?,
+        foreign_tables_res?,
+        foreign_table_options_res?,
+        column_options_res?,
+        user_mappings_res?,
+        user_mapping_options_res?,
+        collations_res?,
+        materialized_views_res?,
+        materialized_view_usage_res?,
+        routines_res?,
+        routine_type_usage_res?,
+        domain_routine_usage_res?,
+        aggregates_res?,
+        extensions_res?,
+        extension_members_res?,
+        schemas_res?,
+        privileges_res?,
+        default_privileges_res?,
+        row_security_res?,
+        policies_res?,
+        comments_res?,
+        indexes_res?,
+        index_columns_res?,
     );
 
     let res = schema::All {
         tables,
         columns,
         views,
+        view_table_usage,
+        triggers,
         table_constraints,
         constraint_column_usage,
         constraint_table_usage,
+        referential_constraints,
         key_column_usage,
         element_types,
         check_constraints,
+        enums,
+        enum_labels,
+        composite_types,
+        composite_type_attributes,
+        domains,
+        domain_constraints,
+        column_attributes,
+        sequences,
+        constraint_details,
+        partitioned_tables,
+        table_inheritance,
+        foreign_data_wrappers,
+        foreign_data_wrapper_options,
+        foreign_servers,
+        foreign_server_options,
+        foreign_tables,
+        foreign_table_options,
+        column_options,
+        user_mappings,
+        user_mapping_options,
+        collations,
+        materialized_views,
+        materialized_view_usage,
+        routines,
+        routine_type_usage,
+        domain_routine_usage,
+        aggregates,
+        extensions,
+        extension_members,
+        schemas,
+        privileges,
+        default_privileges,
+        row_security,
+        policies,
+        comments,
+        indexes,
+        index_columns,
     };
 
     Ok(res)
@@ -1,91 +1,1279 @@
-use std::{collections::btree_set, fmt::Write};
+use std::fmt::Write;
+
+use anyhow::Context;
 
 use crate::{ir, schema};
 
-pub fn table(table: &ir::Table<'_>) -> anyhow::Result<String> {
-    if table.table.table_type != Some("BASE TABLE".to_string()) {
-        Err(anyhow!(
-            "cannot handle table type: {:?}",
-            table.table.table_type
-        ))?
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    /// write `CONSTRAINT name` even when postgres would pick the same name by itself
+    pub always_name_constraints: bool,
+}
+
+pub fn table(table: &ir::Table<'_>, options: &Options) -> anyhow::Result<String> {
+    let create = match table.table.table_type.as_deref() {
+        Some("BASE TABLE") => "CREATE TABLE ",
+        Some("FOREIGN") => "CREATE FOREIGN TABLE ",
+        table_type => Err(anyhow!("cannot handle table type: {:?}", table_type))?,
+    };
+    let partition_parent = table.partition_parent();
+
+    let mut cols: Vec<_> = table.columns.iter().cloned().collect();
+    cols.sort_by_key(|col| (&col.column_name, col.ordinal_position));
+
+    let mut lines = vec![];
+    for col in cols.iter() {
+        match partition_parent {
+            Some(parent) => lines.extend(partition_column(col, parent)?),
+            None if is_local_column(col) => lines.push(column(col)?),
+            None => {}
+        }
     }
-    let mut res: String = "CREATE TABLE ".to_owned();
 
+    let mut constraint_names = inherited_constraint_names(table);
+    for constraint in table
+        .table_constraints
+        .iter()
+        .filter(|c| !is_implicit_not_null(c) && !is_inherited(c) && !is_added_later(c))
+    {
+        match table_constraint(constraint, &constraint_names, options)? {
+            Some(val) => lines.push(val),
+            None => continue,
+        }
+        constraint_names.push(&constraint.constraint_name);
+    }
+
+    for constraint in table.exclusion_constraints.iter().filter(|c| c.is_local) {
+        lines.push(exclusion_constraint(
+            constraint,
+            &constraint_names,
+            options,
+        )?);
+        constraint_names.push(&constraint.constraint_name);
+    }
+
+    let mut res: String = create.to_owned();
     res.push_str(&table_identifier(
-        table.table_schema.as_ref().map(|s| s.as_str()),
+        table.table_schema.as_deref(),
         &table.table_name,
     ));
-    res.push_str(" (");
 
-    let mut cols: Vec<_> = table.columns.iter().cloned().collect();
-    cols.sort_by_key(|col| (&col.column_name, col.ordinal_position));
+    if let Some(parent) = partition_parent {
+        write!(
+            &mut res,
+            " PARTITION OF {}",
+            table_identifier(Some(&parent.parent_schema), &parent.parent_name)
+        )?;
+    }
 
-    let mut inserted_lines = 0;
-    let mut append = |data: &str| {
-        if inserted_lines > 0 {
-            res.push_str(",");
+    // a partition takes all its columns from the parent, so it often has
+    // nothing to list
+    if partition_parent.is_none() && lines.is_empty() {
+        res.push_str(" ()");
+    } else if !lines.is_empty() {
+        res.push_str(" (");
+        for (idx, line) in lines.iter().enumerate() {
+            if idx > 0 {
+                res.push(',');
+            }
+            res.push_str("\n\t");
+            res.push_str(line);
         }
-        res.push_str("\n\t");
-        res.push_str(data);
-        inserted_lines += 1;
-    };
+        res.push_str("\n)");
+    }
 
-    for col in cols.iter() {
-        let val = column(&col)?;
-        append(&val);
+    if let Some(bound) = partition_parent.and_then(|p| p.partition_bound.as_ref()) {
+        write!(&mut res, " {}", bound)?;
+    }
+
+    let inherits: Vec<_> = table
+        .parents
+        .iter()
+        .filter(|p| p.partition_bound.is_none())
+        .map(|p| table_identifier(Some(&p.parent_schema), &p.parent_name))
+        .collect();
+    if !inherits.is_empty() {
+        write!(&mut res, " INHERITS ({})", join(inherits.iter(), ", "))?;
+    }
+
+    if let Some(partitioning) = table.partitioning {
+        write!(&mut res, " PARTITION BY {}", partitioning.partition_key)?;
+    }
+
+    if let Some(foreign_table) = table.foreign_table.as_ref() {
+        write!(
+            &mut res,
+            " SERVER {}",
+            identifier(&foreign_table.foreign_server_name)
+        )?;
+        let table_options = foreign_table
+            .options
+            .iter()
+            .map(|o| (o.option_name.as_str(), o.option_value.as_deref()));
+        if let Some(table_options) = generic_options(table_options) {
+            write!(&mut res, " {}", table_options)?;
+        }
+    }
+    res.push(';');
+
+    Ok(res)
+}
+
+/// NOT NULL and DEFAULT settings a table changed on the columns it inherits.
+/// INHERITS has no way to declare them without also making the column the
+/// table's own, so they are altered right after the table is created.
+pub fn inherited_column_options(table: &ir::Table<'_>) -> anyhow::Result<Vec<String>> {
+    if table.partition_parent().is_some() {
+        return Ok(vec![]);
     }
+    let table_name = table_identifier(table.table_schema.as_deref(), &table.table_name);
 
-    let is_non_nullable_check_constraint = create_is_non_nullable_constraint(&cols);
+    let mut res = vec![];
+    for col in table.columns.iter().filter(|c| !is_local_column(c)) {
+        let parent_cols: Vec<_> = table
+            .parents
+            .iter()
+            .flat_map(|p| p.columns.iter())
+            .filter(|c| c.column_name == col.column_name)
+            .collect();
+        let Some(parent_col) = parent_cols.first() else {
+            continue;
+        };
+        let alter = format!(
+            "ALTER TABLE ONLY {} ALTER COLUMN {}",
+            table_name,
+            identifier(&col.column_name)
+        );
+
+        if col.is_not_null() && !parent_cols.iter().any(|c| c.is_not_null()) {
+            res.push(format!("{} SET NOT NULL;", alter));
+        }
+        match (
+            col.column_default.as_ref(),
+            parent_col.column_default.as_ref(),
+        ) {
+            (Some(default), parent_default) if parent_default != Some(default) => {
+                res.push(format!("{} SET DEFAULT {};", alter, default));
+            }
+            (None, Some(_)) => res.push(format!("{} DROP DEFAULT;", alter)),
+            _ => {}
+        }
+    }
+
+    Ok(res)
+}
+
+/// constraints that are added once all the tables exist: foreign keys, whose
+/// referenced table may come further down, and constraints added with NOT
+/// VALID, which CREATE TABLE would silently validate
+pub fn added_constraints(table: &ir::Table<'_>, options: &Options) -> anyhow::Result<Vec<String>> {
+    let table_name = table_identifier(table.table_schema.as_deref(), &table.table_name);
+
+    // the ones CREATE TABLE made are there already
+    let mut constraint_names: Vec<_> = table
+        .table_constraints
+        .iter()
+        .filter(|c| !is_implicit_not_null(c) && !is_added_later(c))
+        .map(|c| c.constraint_name.as_str())
+        .chain(
+            table
+                .exclusion_constraints
+                .iter()
+                .map(|c| c.constraint_name.as_str()),
+        )
+        .collect();
+
+    let mut res = vec![];
     for constraint in table
         .table_constraints
-        .as_slice()
         .iter()
-        .filter(|c| !is_non_nullable_check_constraint(c))
+        .filter(|c| !is_implicit_not_null(c) && !is_inherited(c) && is_added_later(c))
     {
-        match table_constraint(constraint)? {
-            Some(val) => append(&val),
-            None => continue,
+        if let Some(val) = table_constraint(constraint, &constraint_names, options)? {
+            let not_valid = if is_validated(constraint) {
+                ""
+            } else {
+                " NOT VALID"
+            };
+            res.push(format!(
+                "ALTER TABLE {} ADD {}{};",
+                table_name, val, not_valid
+            ));
         }
+        constraint_names.push(&constraint.constraint_name);
     }
-    drop(append);
 
+    Ok(res)
+}
+
+/// row level security followed by the policies it enforces
+pub fn row_security(table: &ir::Table<'_>) -> anyhow::Result<Vec<String>> {
+    let table_name = table_identifier(table.table_schema.as_deref(), &table.table_name);
+
+    let mut res = vec![];
+    if let Some(row_security) = table.row_security {
+        if row_security.is_enabled {
+            res.push(format!(
+                "ALTER TABLE {} ENABLE ROW LEVEL SECURITY;",
+                table_name
+            ));
+        }
+        if row_security.is_forced {
+            res.push(format!(
+                "ALTER TABLE {} FORCE ROW LEVEL SECURITY;",
+                table_name
+            ));
+        }
+    }
+    for policy in table.policies.iter() {
+        res.push(self::policy(policy, &table_name)?);
+    }
+
+    Ok(res)
+}
+
+fn policy(policy: &schema::Policy, table_name: &str) -> anyhow::Result<String> {
+    // pg_policies names PUBLIC public
+    let roles = policy.roles.iter().map(|role| match role.as_str() {
+        "public" => "PUBLIC".to_owned(),
+        role => identifier(role),
+    });
+    let mut res = format!(
+        "CREATE POLICY {} ON {} AS {} FOR {} TO {}",
+        identifier(&policy.policyname),
+        table_name,
+        policy.permissive,
+        policy.cmd,
+        join(roles, ", ")
+    );
+    if let Some(qual) = policy.qual.as_ref() {
+        write!(&mut res, " USING ({})", qual)?;
+    }
+    if let Some(with_check) = policy.with_check.as_ref() {
+        write!(&mut res, " WITH CHECK ({})", with_check)?;
+    }
+    res.push(';');
+
+    Ok(res)
+}
+
+/// comments on the table, its columns and its constraints
+pub fn table_comments(table: &ir::Table<'_>) -> anyhow::Result<Vec<String>> {
+    let table_name = table_identifier(table.table_schema.as_deref(), &table.table_name);
+    let object_type = match table.table_type.as_deref() {
+        Some("BASE TABLE") => "TABLE",
+        Some("FOREIGN") => "FOREIGN TABLE",
+        table_type => Err(anyhow!("cannot handle table type: {:?}", table_type))?,
+    };
+
+    let mut res = vec![];
+    if let Some(comment) = table.comment {
+        res.push(self::comment(
+            &format!("{} {}", object_type, table_name),
+            comment,
+        ));
+    }
+    res.extend(column_comments(&table_name, &table.columns));
+    let constraints = itertools::chain!(
+        table
+            .table_constraints
+            .iter()
+            .map(|c| (&c.constraint_name, c.comment)),
+        table
+            .exclusion_constraints
+            .iter()
+            .map(|c| (&c.constraint_name, c.comment)),
+    );
+    for (constraint_name, comment) in constraints {
+        if let Some(comment) = comment {
+            res.push(self::comment(
+                &format!(
+                    "CONSTRAINT {} ON {}",
+                    identifier(constraint_name),
+                    table_name
+                ),
+                comment,
+            ));
+        }
+    }
+
+    Ok(res)
+}
+
+/// comments on the view and, for plain views, its columns
+pub fn view_comments(view: &ir::AnyView<'_>) -> anyhow::Result<Vec<String>> {
+    let mut res = vec![];
+    match view {
+        ir::AnyView::View(view) => {
+            let view_name = view
+                .table_name
+                .as_ref()
+                .ok_or_else(|| anyhow!("missing view name"))?;
+            let view_name = table_identifier(view.table_schema.as_deref(), view_name);
+            if let Some(comment) = view.comment {
+                res.push(self::comment(&format!("VIEW {}", view_name), comment));
+            }
+            res.extend(column_comments(&view_name, &view.columns));
+        }
+        ir::AnyView::MaterializedView(view) => {
+            let view_name = table_identifier(Some(&view.schemaname), &view.matviewname);
+            if let Some(comment) = view.comment {
+                res.push(self::comment(
+                    &format!("MATERIALIZED VIEW {}", view_name),
+                    comment,
+                ));
+            }
+            for comment in view.column_comments.iter() {
+                let column_name = comment
+                    .member_name
+                    .as_ref()
+                    .ok_or_else(|| anyhow!("missing column name"))?;
+                res.push(self::comment(
+                    &format!("COLUMN {}.{}", view_name, identifier(column_name)),
+                    &comment.description,
+                ));
+            }
+        }
+    }
+
+    Ok(res)
+}
+
+pub fn enum_comment(enum_type: &ir::Enum<'_>) -> anyhow::Result<Option<String>> {
+    let res = enum_type.comment.map(|comment| {
+        let type_name = table_identifier(Some(&enum_type.type_schema), &enum_type.type_name);
+        self::comment(&format!("TYPE {}", type_name), comment)
+    });
+
+    Ok(res)
+}
+
+pub fn composite_type_comment(
+    composite_type: &ir::CompositeType<'_>,
+) -> anyhow::Result<Option<String>> {
+    let res = composite_type.comment.map(|comment| {
+        let type_name =
+            table_identifier(Some(&composite_type.type_schema), &composite_type.type_name);
+        self::comment(&format!("TYPE {}", type_name), comment)
+    });
+
+    Ok(res)
+}
+
+/// comments on the domain and its constraints
+pub fn domain_comments(domain: &ir::Domain<'_>) -> anyhow::Result<Vec<String>> {
+    let domain_name = domain
+        .domain_name
+        .as_ref()
+        .ok_or_else(|| anyhow!("missing domain name"))?;
+    let domain_name = table_identifier(domain.domain_schema.as_deref(), domain_name);
+
+    let mut res = vec![];
+    if let Some(comment) = domain.comment {
+        res.push(self::comment(&format!("DOMAIN {}", domain_name), comment));
+    }
+    for constraint in domain.constraints.iter() {
+        if let Some(comment) = constraint.comment {
+            res.push(self::comment(
+                &format!(
+                    "CONSTRAINT {} ON DOMAIN {}",
+                    identifier(&constraint.constraint_name),
+                    domain_name
+                ),
+                comment,
+            ));
+        }
+    }
+
+    Ok(res)
+}
+
+pub fn routine_comment(routine: &ir::Routine<'_>) -> anyhow::Result<Option<String>> {
+    let object_type = match routine.kind.as_str() {
+        "p" => "PROCEDURE",
+        _ => "FUNCTION",
+    };
+    let res = routine.comment.map(|comment| {
+        let routine_name = table_identifier(Some(&routine.routine_schema), &routine.routine_name);
+        self::comment(
+            &format!(
+                "{} {}({})",
+                object_type, routine_name, routine.identity_arguments
+            ),
+            comment,
+        )
+    });
+
+    Ok(res)
+}
+
+pub fn aggregate_comment(aggregate: &ir::Aggregate<'_>) -> anyhow::Result<Option<String>> {
+    let res = aggregate.comment.map(|comment| {
+        let aggregate_name =
+            table_identifier(Some(&aggregate.aggregate_schema), &aggregate.aggregate_name);
+        self::comment(
+            &format!(
+                "AGGREGATE {}({})",
+                aggregate_name,
+                aggregate_arguments(&aggregate.identity_arguments)
+            ),
+            comment,
+        )
+    });
+
+    Ok(res)
+}
+
+fn column_comments(table_name: &str, columns: &[ir::Column<'_>]) -> Vec<String> {
+    columns
+        .iter()
+        .filter_map(|column| {
+            let comment = column.comment?;
+            Some(self::comment(
+                &format!("COLUMN {}.{}", table_name, identifier(&column.column_name)),
+                comment,
+            ))
+        })
+        .collect()
+}
+
+fn comment(object: &str, comment: &str) -> String {
+    format!("COMMENT ON {} IS {};", object, literal(comment))
+}
+
+/// an aggregate without arguments is written as agg(*)
+fn aggregate_arguments(arguments: &str) -> &str {
+    match arguments {
+        "" => "*",
+        arguments => arguments,
+    }
+}
+
+pub fn foreign_data_wrapper(wrapper: &ir::ForeignDataWrapper<'_>) -> anyhow::Result<String> {
+    let mut res = format!(
+        "CREATE FOREIGN DATA WRAPPER {}",
+        identifier(&wrapper.foreign_data_wrapper_name)
+    );
+    if let Some(handler) = wrapper.handler.as_ref() {
+        write!(&mut res, " HANDLER {}", handler)?;
+    }
+    if let Some(validator) = wrapper.validator.as_ref() {
+        write!(&mut res, " VALIDATOR {}", validator)?;
+    }
+
+    let wrapper_options = wrapper
+        .options
+        .iter()
+        .map(|o| (o.option_name.as_str(), o.option_value.as_deref()));
+    if let Some(wrapper_options) = generic_options(wrapper_options) {
+        write!(&mut res, " {}", wrapper_options)?;
+    }
+    res.push(';');
+
+    Ok(res)
+}
+
+pub fn foreign_server(server: &ir::ForeignServer<'_>) -> anyhow::Result<String> {
+    let mut res = format!("CREATE SERVER {}", identifier(&server.foreign_server_name));
+    if let Some(server_type) = server.foreign_server_type.as_ref() {
+        write!(&mut res, " TYPE {}", literal(server_type))?;
+    }
+    if let Some(version) = server.foreign_server_version.as_ref() {
+        write!(&mut res, " VERSION {}", literal(version))?;
+    }
+    write!(
+        &mut res,
+        " FOREIGN DATA WRAPPER {}",
+        identifier(&server.foreign_data_wrapper_name)
+    )?;
+
+    let server_options = server
+        .options
+        .iter()
+        .map(|o| (o.option_name.as_str(), o.option_value.as_deref()));
+    if let Some(server_options) = generic_options(server_options) {
+        write!(&mut res, " {}", server_options)?;
+    }
+    res.push(';');
+
+    Ok(res)
+}
+
+/// user mappings tend to hold credentials, so only the option names are kept
+pub fn user_mapping(user_mapping: &ir::UserMapping<'_>) -> anyhow::Result<String> {
+    const REDACTED: &str = "<redacted>";
+
+    let user = match user_mapping.authorization_identifier.as_str() {
+        "PUBLIC" => "PUBLIC".to_owned(),
+        user => identifier(user),
+    };
+    let mut res = format!(
+        "CREATE USER MAPPING FOR {} SERVER {}",
+        user,
+        identifier(&user_mapping.foreign_server_name)
+    );
+
+    let mapping_options = user_mapping
+        .options
+        .iter()
+        .map(|o| (o.option_name.as_str(), Some(REDACTED)));
+    if let Some(mapping_options) = generic_options(mapping_options) {
+        write!(&mut res, " {}", mapping_options)?;
+    }
+    res.push(';');
+
+    Ok(res)
+}
+
+pub fn create_schema(schema: &ir::Schema<'_>) -> anyhow::Result<String> {
+    let res = format!(
+        "CREATE SCHEMA {} AUTHORIZATION {};",
+        identifier(&schema.schema_name),
+        identifier(&schema.schema_owner)
+    );
+
+    Ok(res)
+}
+
+pub fn privilege(privilege: &ir::Privilege<'_>) -> anyhow::Result<String> {
+    let mut privilege_types: Vec<String> = privilege
+        .privilege_types
+        .iter()
+        .map(|p| p.to_string())
+        .collect();
+    let object = match privilege.object_type.as_str() {
+        "TABLE" | "SEQUENCE" => format!(
+            "{} {}",
+            privilege.object_type,
+            table_identifier(Some(&privilege.object_schema), &privilege.object_name)
+        ),
+        "COLUMN" => {
+            let column_name = privilege.column_name.as_ref().ok_or_else(|| {
+                anyhow!("missing column for privilege on {}", privilege.object_name)
+            })?;
+            for privilege_type in privilege_types.iter_mut() {
+                *privilege_type = format!("{} ({})", privilege_type, identifier(column_name));
+            }
+            format!(
+                "TABLE {}",
+                table_identifier(Some(&privilege.object_schema), &privilege.object_name)
+            )
+        }
+        "SCHEMA" => format!("SCHEMA {}", identifier(&privilege.object_name)),
+        "FUNCTION" | "PROCEDURE" => format!(
+            "{} {}({})",
+            privilege.object_type,
+            table_identifier(Some(&privilege.object_schema), &privilege.object_name),
+            privilege.identity_arguments.as_deref().unwrap_or_default()
+        ),
+        object_type => Err(anyhow!("cannot handle privileges on: {}", object_type))?,
+    };
+
+    let res = grant_or_revoke(
+        &privilege_types,
+        &object,
+        privilege.grantee.as_deref(),
+        privilege.is_grantable,
+        privilege.is_grant,
+    );
+
+    Ok(res)
+}
+
+pub fn default_privilege(default_privilege: &ir::DefaultPrivilege<'_>) -> anyhow::Result<String> {
+    let mut res = format!(
+        "ALTER DEFAULT PRIVILEGES FOR ROLE {}",
+        identifier(&default_privilege.role_name)
+    );
+    if let Some(schema_name) = default_privilege.schema_name.as_ref() {
+        write!(&mut res, " IN SCHEMA {}", identifier(schema_name))?;
+    }
+    write!(
+        &mut res,
+        " {}",
+        grant_or_revoke(
+            &default_privilege.privilege_types,
+            &default_privilege.object_type,
+            default_privilege.grantee.as_deref(),
+            default_privilege.is_grantable,
+            default_privilege.is_grant,
+        )
+    )?;
+
+    Ok(res)
+}
+
+/// a grantee of none stands for PUBLIC
+fn grant_or_revoke(
+    privilege_types: &[impl AsRef<str>],
+    object: &str,
+    grantee: Option<&str>,
+    is_grantable: bool,
+    is_grant: bool,
+) -> String {
+    let grantee = grantee
+        .map(identifier)
+        .unwrap_or_else(|| "PUBLIC".to_owned());
+    let privilege_types = join(privilege_types.iter(), ", ");
+    match (is_grant, is_grantable) {
+        (true, true) => format!(
+            "GRANT {} ON {} TO {} WITH GRANT OPTION;",
+            privilege_types, object, grantee
+        ),
+        (true, false) => format!("GRANT {} ON {} TO {};", privilege_types, object, grantee),
+        (false, _) => format!("REVOKE {} ON {} FROM {};", privilege_types, object, grantee),
+    }
+}
+
+pub fn extension(extension: &ir::Extension<'_>) -> anyhow::Result<String> {
+    let res = format!(
+        "CREATE EXTENSION IF NOT EXISTS {} WITH SCHEMA {} VERSION {};",
+        identifier(&extension.extension_name),
+        identifier(&extension.extension_schema),
+        literal(&extension.extension_version)
+    );
+
+    Ok(res)
+}
+
+pub fn collation(collation: &ir::Collation<'_>) -> anyhow::Result<String> {
+    let mut properties = vec![];
+    match collation.provider.as_str() {
+        "icu" => {
+            let locale = collation.icu_locale.as_ref().ok_or_else(|| {
+                anyhow!(
+                    "missing icu locale for collation {}",
+                    collation.collation_name
+                )
+            })?;
+            properties.push("provider = icu".to_owned());
+            properties.push(format!("locale = {}", literal(locale)));
+        }
+        "libc" => {
+            let (lc_collate, lc_ctype) = collation
+                .lc_collate
+                .as_ref()
+                .zip(collation.lc_ctype.as_ref())
+                .ok_or_else(|| {
+                    anyhow!("missing locale for collation {}", collation.collation_name)
+                })?;
+            if lc_collate == lc_ctype {
+                properties.push(format!("locale = {}", literal(lc_collate)));
+            } else {
+                properties.push(format!("lc_collate = {}", literal(lc_collate)));
+                properties.push(format!("lc_ctype = {}", literal(lc_ctype)));
+            }
+        }
+        provider => Err(anyhow!("cannot handle collation provider: {}", provider))?,
+    }
+    if !collation.is_deterministic {
+        properties.push("deterministic = false".to_owned());
+    }
+
+    let res = format!(
+        "CREATE COLLATION {} ({});",
+        table_identifier(Some(&collation.collation_schema), &collation.collation_name),
+        join(properties.iter(), ", ")
+    );
+
+    Ok(res)
+}
+
+pub fn enum_type(enum_type: &ir::Enum<'_>) -> anyhow::Result<String> {
+    let labels = join(enum_type.labels.iter().map(|l| literal(&l.label)), ", ");
+    let res = format!(
+        "CREATE TYPE {} AS ENUM ({});",
+        table_identifier(Some(&enum_type.type_schema), &enum_type.type_name),
+        labels
+    );
+
+    Ok(res)
+}
+
+pub fn domain(domain: &ir::Domain<'_>) -> anyhow::Result<String> {
+    let domain_name = domain
+        .domain_name
+        .as_ref()
+        .ok_or_else(|| anyhow!("missing domain name"))?;
+
+    let data_type = match domain.array_data_type.as_ref() {
+        Some(array_data_type) => array_data_type.clone(),
+        None => data_type(domain.domain, domain.element_type, 1)?,
+    };
+    let mut res = format!(
+        "CREATE DOMAIN {} AS {}",
+        table_identifier(domain.domain_schema.as_deref(), domain_name),
+        data_type
+    );
+
+    if let Some(collation) = domain.collation_name.as_ref() {
+        write!(
+            &mut res,
+            " COLLATE {}",
+            qualified_identifier(domain.collation_schema.as_deref(), collation)
+        )?;
+    }
+    if let Some(default) = domain.domain_default.as_ref() {
+        write!(&mut res, " DEFAULT {}", default)?;
+    }
+    if domain.is_not_null {
+        res.push_str(" NOT NULL");
+    }
+
+    for constraint in domain.constraints.iter() {
+        for check in constraint.check_constraints.iter() {
+            // check clauses come from pg_get_constraintdef, which already
+            // wraps the expression in parentheses.
+            write!(
+                &mut res,
+                "\n\tCONSTRAINT {} CHECK {}",
+                identifier(&constraint.constraint_name),
+                check.check_clause
+            )?;
+        }
+    }
+    res.push(';');
+
+    Ok(res)
+}
+
+pub fn composite_type(composite_type: &ir::CompositeType<'_>) -> anyhow::Result<String> {
+    let mut res = format!(
+        "CREATE TYPE {} AS (",
+        table_identifier(Some(&composite_type.type_schema), &composite_type.type_name)
+    );
+
+    for (idx, attribute) in composite_type.attributes.iter().enumerate() {
+        if idx > 0 {
+            res.push(',');
+        }
+        write!(
+            &mut res,
+            "\n\t{} {}",
+            identifier(&attribute.attribute_name),
+            attribute.data_type
+        )?;
+    }
     res.push_str("\n);");
 
     Ok(res)
 }
 
+pub fn sequence(sequence: &ir::Sequence<'_>) -> anyhow::Result<String> {
+    let res = format!(
+        "CREATE SEQUENCE {} AS {} START WITH {} INCREMENT BY {} MINVALUE {} MAXVALUE {} CACHE {}{};",
+        table_identifier(Some(&sequence.sequence_schema), &sequence.sequence_name),
+        sequence.data_type,
+        sequence.start_value,
+        sequence.increment,
+        sequence.minimum_value,
+        sequence.maximum_value,
+        sequence.cache_size,
+        if sequence.cycle_option {
+            " CYCLE"
+        } else {
+            " NO CYCLE"
+        },
+    );
+
+    Ok(res)
+}
+
+/// the OWNED BY link can only be created once the owning table exists.
+pub fn sequence_owner(sequence: &ir::Sequence<'_>) -> anyhow::Result<Option<String>> {
+    let owner = match sequence.owner.as_ref() {
+        Some(owner) => owner,
+        None => return Ok(None),
+    };
+
+    let res = format!(
+        "ALTER SEQUENCE {} OWNED BY {}.{};",
+        table_identifier(Some(&sequence.sequence_schema), &sequence.sequence_name),
+        table_identifier(owner.table_schema.as_deref(), &owner.table_name),
+        identifier(&owner.column_name)
+    );
+
+    Ok(Some(res))
+}
+
+/// Returns `true` if the sequence is implied by its owning column, either as
+/// the sequence behind a serial column or the one behind an identity column.
+pub fn is_implicit_sequence(sequence: &ir::Sequence<'_>) -> bool {
+    sequence.is_identity || sequence.owner.as_ref().and_then(serial_type).is_some()
+}
+
+pub fn view(view: &ir::View<'_>) -> anyhow::Result<String> {
+    let view_name = view
+        .table_name
+        .as_ref()
+        .ok_or_else(|| anyhow!("missing view name"))?;
+    let definition = view
+        .view_definition
+        .as_ref()
+        .ok_or_else(|| anyhow!("missing definition for view {}", view_name))?;
+
+    let mut res = format!(
+        "CREATE VIEW {}",
+        table_identifier(view.table_schema.as_deref(), view_name)
+    );
+    if !view.columns.is_empty() {
+        let cols = join(
+            view.columns.iter().map(|c| identifier(&c.column_name)),
+            ", ",
+        );
+        write!(&mut res, " ({})", cols)?;
+    }
+    write!(&mut res, " AS\n{}", definition.trim().trim_end_matches(';'))?;
+
+    match view.check_option.as_deref() {
+        None | Some("NONE") => {}
+        Some(check_option) => write!(&mut res, "\nWITH {} CHECK OPTION", check_option)?,
+    }
+    res.push(';');
+
+    Ok(res)
+}
+
+pub fn materialized_view(view: &ir::MaterializedView<'_>) -> anyhow::Result<String> {
+    let definition = view.definition.as_ref().ok_or_else(|| {
+        anyhow!(
+            "missing definition for materialized view {}",
+            view.matviewname
+        )
+    })?;
+
+    let mut res = format!(
+        "CREATE MATERIALIZED VIEW {} AS\n{}",
+        table_identifier(Some(&view.schemaname), &view.matviewname),
+        definition.trim().trim_end_matches(';')
+    );
+    match view.ispopulated {
+        true => res.push_str("\nWITH DATA;"),
+        false => res.push_str("\nWITH NO DATA;"),
+    }
+
+    Ok(res)
+}
+
+pub fn routine(routine: &ir::Routine<'_>) -> anyhow::Result<String> {
+    // pg_get_functiondef already writes the whole CREATE OR REPLACE statement
+    let res = format!("{};", routine.definition.trim_end());
+
+    Ok(res)
+}
+
+pub fn aggregate(aggregate: &ir::Aggregate<'_>) -> anyhow::Result<String> {
+    // read only is the default for normal aggregates, read write for ordered-set ones
+    let default_modify = match aggregate.kind.as_str() {
+        "n" => "r",
+        _ => "w",
+    };
+    let modify = |modify: &str| match modify {
+        "r" => Ok("READ_ONLY"),
+        "s" => Ok("SHAREABLE"),
+        "w" => Ok("READ_WRITE"),
+        modify => Err(anyhow!("cannot handle final function modify: {}", modify)),
+    };
+
+    let mut properties = vec![
+        format!("SFUNC = {}", aggregate.transition_function),
+        format!("STYPE = {}", aggregate.transition_type),
+    ];
+    if aggregate.transition_space != 0 {
+        properties.push(format!("SSPACE = {}", aggregate.transition_space));
+    }
+    if let Some(final_function) = aggregate.final_function.as_ref() {
+        properties.push(format!("FINALFUNC = {}", final_function));
+    }
+    if aggregate.final_function_extra {
+        properties.push("FINALFUNC_EXTRA".to_owned());
+    }
+    if aggregate.final_function_modify != default_modify {
+        properties.push(format!(
+            "FINALFUNC_MODIFY = {}",
+            modify(&aggregate.final_function_modify)?
+        ));
+    }
+    if let Some(combine_function) = aggregate.combine_function.as_ref() {
+        properties.push(format!("COMBINEFUNC = {}", combine_function));
+    }
+    if let Some(serial_function) = aggregate.serial_function.as_ref() {
+        properties.push(format!("SERIALFUNC = {}", serial_function));
+    }
+    if let Some(deserial_function) = aggregate.deserial_function.as_ref() {
+        properties.push(format!("DESERIALFUNC = {}", deserial_function));
+    }
+    if let Some(initial_condition) = aggregate.initial_condition.as_ref() {
+        properties.push(format!("INITCOND = {}", literal(initial_condition)));
+    }
+
+    if let Some(transition_function) = aggregate.moving_transition_function.as_ref() {
+        properties.push(format!("MSFUNC = {}", transition_function));
+    }
+    if let Some(inverse_function) = aggregate.moving_inverse_function.as_ref() {
+        properties.push(format!("MINVFUNC = {}", inverse_function));
+    }
+    if let Some(transition_type) = aggregate.moving_transition_type.as_ref() {
+        properties.push(format!("MSTYPE = {}", transition_type));
+    }
+    if aggregate.moving_transition_space != 0 {
+        properties.push(format!("MSSPACE = {}", aggregate.moving_transition_space));
+    }
+    if let Some(final_function) = aggregate.moving_final_function.as_ref() {
+        properties.push(format!("MFINALFUNC = {}", final_function));
+    }
+    if aggregate.moving_final_function_extra {
+        properties.push("MFINALFUNC_EXTRA".to_owned());
+    }
+    if aggregate.moving_transition_function.is_some()
+        && aggregate.moving_final_function_modify != default_modify
+    {
+        properties.push(format!(
+            "MFINALFUNC_MODIFY = {}",
+            modify(&aggregate.moving_final_function_modify)?
+        ));
+    }
+    if let Some(initial_condition) = aggregate.moving_initial_condition.as_ref() {
+        properties.push(format!("MINITCOND = {}", literal(initial_condition)));
+    }
+
+    if let Some(sort_operator) = aggregate.sort_operator.as_ref() {
+        match sort_operator.strip_prefix("pg_catalog.") {
+            Some(operator) => properties.push(format!("SORTOP = {}", operator)),
+            None => properties.push(format!("SORTOP = OPERATOR({})", sort_operator)),
+        }
+    }
+    match aggregate.parallel.as_str() {
+        "s" => properties.push("PARALLEL = SAFE".to_owned()),
+        "r" => properties.push("PARALLEL = RESTRICTED".to_owned()),
+        "u" => {}
+        parallel => Err(anyhow!("cannot handle parallel safety: {}", parallel))?,
+    }
+    if aggregate.kind == "h" {
+        properties.push("HYPOTHETICAL".to_owned());
+    }
+
+    let res = format!(
+        "CREATE AGGREGATE {}({}) (\n\t{}\n);",
+        table_identifier(Some(&aggregate.aggregate_schema), &aggregate.aggregate_name),
+        aggregate_arguments(&aggregate.arguments),
+        join(properties.iter(), ",\n\t")
+    );
+
+    Ok(res)
+}
+
+pub fn index(index: &ir::Index<'_>) -> anyhow::Result<String> {
+    let mut res: String = "CREATE ".to_owned();
+    if index.is_unique {
+        res.push_str("UNIQUE ");
+    }
+
+    write!(
+        &mut res,
+        "INDEX {} ON {} USING {} (",
+        identifier(&index.index_name),
+        table_identifier(Some(&index.table_schema), &index.table_name),
+        index.index_method
+    )?;
+
+    let key_columns = index
+        .columns
+        .iter()
+        .filter(|c| !c.is_included)
+        .map(|c| index_column(c));
+    res.push_str(&join(key_columns, ", "));
+    res.push(')');
+
+    let included_columns: Vec<_> = index.columns.iter().filter(|c| c.is_included).collect();
+    if !included_columns.is_empty() {
+        let cols = join(included_columns.iter().map(|c| index_column_name(c)), ", ");
+        write!(&mut res, " INCLUDE ({})", cols)?;
+    }
+
+    if let Some(predicate) = index.predicate.as_ref() {
+        write!(&mut res, " WHERE {}", predicate)?;
+    }
+    res.push(';');
+
+    Ok(res)
+}
+
+pub fn trigger(trigger: &ir::Trigger<'_>) -> anyhow::Result<String> {
+    Ok(format!("{};", trigger.definition))
+}
+
+fn index_column_name(col: &schema::IndexColumn) -> String {
+    match col.column_name.as_ref() {
+        Some(name) => identifier(name),
+        None => format!("({})", col.definition),
+    }
+}
+
+fn index_column(col: &schema::IndexColumn) -> String {
+    let mut res = index_column_name(col);
+
+    if let Some(collation) = col.collation_name.as_ref() {
+        res.push_str(" COLLATE ");
+        res.push_str(&qualified_identifier(
+            col.collation_schema.as_deref(),
+            collation,
+        ));
+    }
+
+    if let Some(opclass) = col.opclass_name.as_ref() {
+        res.push(' ');
+        res.push_str(&qualified_identifier(
+            col.opclass_schema.as_deref(),
+            opclass,
+        ));
+    }
+
+    // btree's default ordering is ASC NULLS LAST, and DESC implies NULLS FIRST
+    match (col.is_descending, col.is_nulls_first) {
+        (false, false) => {}
+        (false, true) => res.push_str(" NULLS FIRST"),
+        (true, true) => res.push_str(" DESC"),
+        (true, false) => res.push_str(" DESC NULLS LAST"),
+    }
+
+    res
+}
+
 fn schema(data: Option<&str>) -> String {
-    let schema = data
-        .filter(|x| *x != "public")
+    data.filter(|x| *x != "public")
         .map(identifier)
-        .unwrap_or_default();
-    schema
+        .unwrap_or_default()
 }
 
 fn table_identifier(table_schema: Option<&str>, table_name: &str) -> String {
     let mut res = schema(table_schema);
-    if res != "" {
-        res.push_str(".")
+    if !res.is_empty() {
+        res.push('.')
     }
     res.push_str(&identifier(table_name));
     res
 }
 
+/// like `table_identifier`, but `pg_catalog` is hidden as well since its
+/// objects (collations, operator classes, ...) are always on the search path.
+fn qualified_identifier(object_schema: Option<&str>, object_name: &str) -> String {
+    table_identifier(object_schema.filter(|s| *s != "pg_catalog"), object_name)
+}
+
 // TODO be less aggressive with identifers
 fn identifier(data: &str) -> String {
     format!("\"{}\"", data)
 }
 
-fn is_serial_expression(table_name: &str, column_name: &str, default_expression: &str) -> bool {
-    return format!("nextval('{}_{}_seq'::regclass)", table_name, column_name)
-        == default_expression;
+/// a string with backslashes is written as an escape string, which reads the
+/// same whether standard_conforming_strings is on or off
+fn literal(data: &str) -> String {
+    let quoted = data.replace('\'', "''");
+    if quoted.contains('\\') {
+        format!("E'{}'", quoted.replace('\\', "\\\\"))
+    } else {
+        format!("'{}'", quoted)
+    }
+}
+
+/// mirrors postgres' makeObjectName, which derives implicit names such as
+/// `<table>_<column>_seq`. the parts are truncated so the result fits in 63
+/// bytes, shortening the longer part first.
+fn default_object_name(name1: &str, name2: Option<&str>, label: &str) -> String {
+    const MAX_IDENTIFIER_LENGTH: usize = 63;
+
+    let overhead = label.len() + 1 + name2.map_or(0, |_| 1);
+    let available = MAX_IDENTIFIER_LENGTH.saturating_sub(overhead);
+
+    let mut name1_len = name1.len();
+    let mut name2_len = name2.map_or(0, str::len);
+    while name1_len + name2_len > available {
+        if name1_len > name2_len {
+            name1_len -= 1;
+        } else {
+            name2_len -= 1;
+        }
+    }
+
+    let mut res = clip(name1, name1_len).to_owned();
+    if let Some(name2) = name2 {
+        res.push('_');
+        res.push_str(clip(name2, name2_len));
+    }
+    res.push('_');
+    res.push_str(label);
+    res
+}
+
+/// truncates to at most `len` bytes without splitting a character.
+fn clip(data: &str, len: usize) -> &str {
+    let mut end = len.min(data.len());
+    while !data.is_char_boundary(end) {
+        end -= 1;
+    }
+    &data[..end]
+}
+
+/// returns the serial pseudo-type for a column whose owned sequence is
+/// exactly what `CREATE TABLE` would have made for it.
+fn serial_type(col: &ir::Column<'_>) -> Option<&'static str> {
+    let sequence = col.owned_sequence?;
+    if col.domain_name.is_some() || sequence.is_identity || !sequence.is_owner_default {
+        return None;
+    }
+
+    let (serial_type, maximum_value) = match (col.data_type.as_str(), sequence.data_type.as_str()) {
+        ("smallint", "smallint") => ("smallserial", i16::MAX as i64),
+        ("integer", "integer") => ("serial", i32::MAX as i64),
+        ("bigint", "bigint") => ("bigserial", i64::MAX),
+        _ => return None,
+    };
+
+    let is_default_sequence = col.table_schema.as_ref() == Some(&sequence.sequence_schema)
+        && sequence.sequence_name
+            == default_object_name(&col.table_name, Some(&col.column_name), "seq")
+        && sequence.start_value == 1
+        && sequence.increment == 1
+        && sequence.minimum_value == 1
+        && sequence.maximum_value == maximum_value
+        && sequence.cache_size == 1
+        && !sequence.cycle_option;
+
+    is_default_sequence.then_some(serial_type)
+}
+
+/// `constraint_names` are the table's constraints that exist by the time this
+/// one is created, which postgres keeps clear of when it picks a default name
+fn table_constraint(
+    item: &ir::TableConstraint<'_>,
+    constraint_names: &[&str],
+    options: &Options,
+) -> anyhow::Result<Option<String>> {
+    let definition = match constraint_definition(item)? {
+        Some(definition) => definition,
+        None => return Ok(None),
+    };
+
+    let mut res = named_constraint(
+        &item.constraint_name,
+        default_constraint_name(item, constraint_names),
+        definition,
+        options,
+    );
+    deferrable(
+        &mut res,
+        item.is_deferrable.unwrap_or_default().is_yes(),
+        item.initially_deferred.unwrap_or_default().is_yes(),
+    );
+
+    Ok(Some(res))
+}
+
+fn exclusion_constraint(
+    item: &ir::ExclusionConstraint<'_>,
+    constraint_names: &[&str],
+    options: &Options,
+) -> anyhow::Result<String> {
+    let operators = item.exclusion_operators.as_ref().ok_or_else(|| {
+        anyhow!(
+            "missing operators for exclusion constraint {}",
+            item.constraint_name
+        )
+    })?;
+    let key_columns: Vec<_> = item
+        .index
+        .columns
+        .iter()
+        .filter(|c| !c.is_included)
+        .collect();
+    if key_columns.len() != operators.len() {
+        Err(anyhow!(
+            "exclusion constraint {} has {} columns but {} operators",
+            item.constraint_name,
+            key_columns.len(),
+            operators.len()
+        ))?
+    }
+
+    let elements = key_columns.iter().zip(operators).map(|(col, operator)| {
+        match operator.strip_prefix("pg_catalog.") {
+            Some(operator) => format!("{} WITH {}", index_column(col), operator),
+            None => format!("{} WITH OPERATOR({})", index_column(col), operator),
+        }
+    });
+    let mut definition = format!(
+        "EXCLUDE USING {} ({})",
+        item.index.index_method,
+        join(elements, ", ")
+    );
+
+    let included_columns: Vec<_> = item
+        .index
+        .columns
+        .iter()
+        .filter(|c| c.is_included)
+        .collect();
+    if !included_columns.is_empty() {
+        let cols = join(included_columns.iter().map(|c| index_column_name(c)), ", ");
+        write!(&mut definition, " INCLUDE ({})", cols)?;
+    }
+
+    if let Some(predicate) = item.index.predicate.as_ref() {
+        write!(&mut definition, " WHERE ({})", predicate)?;
+    }
+
+    // an expression's part of the default name depends on how it was written,
+    // so those constraints always keep their name
+    let column_names: Option<Vec<_>> = item
+        .index
+        .columns
+        .iter()
+        .map(|c| c.column_name.as_deref())
+        .collect();
+    let default_name = column_names.map(|column_names| {
+        default_name(
+            &item.constraint_name,
+            &item.table_name,
+            Some(&index_name_addition(&column_names)),
+            "excl",
+            constraint_names,
+        )
+    });
+
+    let mut res = named_constraint(&item.constraint_name, default_name, definition, options);
+    deferrable(&mut res, item.is_deferrable, item.is_initially_deferred);
+
+    Ok(res)
+}
+
+fn named_constraint(
+    name: &str,
+    default_name: Option<String>,
+    definition: String,
+    options: &Options,
+) -> String {
+    if options.always_name_constraints || default_name.as_deref() != Some(name) {
+        format!("CONSTRAINT {} {}", identifier(name), definition)
+    } else {
+        definition
+    }
+}
+
+fn deferrable(res: &mut String, is_deferrable: bool, is_initially_deferred: bool) {
+    if is_deferrable {
+        res.push_str(" DEFERRABLE");
+    }
+    if is_initially_deferred {
+        res.push_str(" INITIALLY DEFERRED");
+    }
 }
 
-fn table_constraint(item: &ir::TableConstraint<'_>) -> anyhow::Result<Option<String>> {
+fn constraint_definition(item: &ir::TableConstraint<'_>) -> anyhow::Result<Option<String>> {
     match item.constraint_type.as_str() {
         "PRIMARY KEY" => {
             let cols = join(
-                item.columns.iter().map(|c| identifier(&c.column_name)),
+                key_columns(item).iter().map(|c| identifier(&c.column_name)),
                 ", ",
             );
             let res = format!("PRIMARY KEY ({})", cols);
@@ -93,42 +1281,88 @@ fn table_constraint(item: &ir::TableConstraint<'_>) -> anyhow::Result<Option<Str
         }
         "UNIQUE" => {
             let cols = join(
-                item.columns.iter().map(|c| identifier(&c.column_name)),
+                key_columns(item).iter().map(|c| identifier(&c.column_name)),
                 ", ",
             );
-            let res = format!("UNIQUE ({})", cols);
+            let res = match item.nulls_distinct {
+                Some(schema::YesNo::No) => format!("UNIQUE NULLS NOT DISTINCT ({})", cols),
+                _ => format!("UNIQUE ({})", cols),
+            };
             Ok(Some(res))
         }
         "FOREIGN KEY" => {
-            let reference_table: &schema::Table = item.tables.get(0).ok_or_else(|| {
+            let reference_table: &schema::Table = item.tables.first().ok_or_else(|| {
                 anyhow!(
                     "missing foreign key reference table for constraint {}",
                     item.constraint_name
                 )
             })?;
 
-            let mut key_cols = (*item.key_columns).clone();
-            key_cols.sort_by_key(|c| c.usage.ordinal_position);
+            let key_cols = key_columns(item);
 
             let key_cols_string = join(key_cols.iter().map(|c| identifier(&c.column_name)), ", ");
-            let mut reference_cols = (*item.columns).clone();
-            reference_cols.sort_by_key(|c| {
-                key_cols
-                    .iter()
-                    .enumerate()
-                    .find(|k| k.1.usage.position_in_unique_constraint == Some(c.ordinal_position))
-                    .map(|r| r.0)
-            });
-
-            let reference_cols_string = join(
-                reference_cols.iter().map(|c| identifier(&c.column_name)),
-                ", ",
-            );
+            // information_schema only gives the position of a referenced column
+            // within the referenced key, so the pairing comes from confkey
+            let reference_cols = item
+                .detail
+                .and_then(|d| d.referenced_columns.as_ref())
+                .ok_or_else(|| {
+                    anyhow!(
+                        "missing referenced columns for constraint {}",
+                        item.constraint_name
+                    )
+                })?;
+            let reference_cols_string = join(reference_cols.iter().map(|c| identifier(c)), ", ");
 
-            let res = format!(
+            let mut res = format!(
                 "FOREIGN KEY ({}) REFERENCES {} ({})",
-                key_cols_string, reference_table.table_name, reference_cols_string
+                key_cols_string,
+                table_identifier(
+                    reference_table.table_schema.as_deref(),
+                    &reference_table.table_name
+                ),
+                reference_cols_string
             );
+
+            if let Some(referential) = item.referential_constraint {
+                match referential.match_option.as_str() {
+                    "FULL" => res.push_str(" MATCH FULL"),
+                    "PARTIAL" => res.push_str(" MATCH PARTIAL"),
+                    _ => {}
+                }
+                if referential.update_rule != "NO ACTION" {
+                    write!(&mut res, " ON UPDATE {}", referential.update_rule)?;
+                }
+                if referential.delete_rule != "NO ACTION" {
+                    write!(&mut res, " ON DELETE {}", referential.delete_rule)?;
+                    if let Some(set_columns) =
+                        item.detail.and_then(|d| d.delete_set_columns.as_ref())
+                    {
+                        let set_columns = join(set_columns.iter().map(|c| identifier(c)), ", ");
+                        write!(&mut res, " ({})", set_columns)?;
+                    }
+                }
+            }
+            Ok(Some(res))
+        }
+        "CHECK" => {
+            let detail = item.detail.ok_or_else(|| {
+                anyhow!(
+                    "missing check expression for constraint {}",
+                    item.constraint_name
+                )
+            })?;
+            let expression = detail.check_expression.as_ref().ok_or_else(|| {
+                anyhow!(
+                    "missing check expression for constraint {}",
+                    item.constraint_name
+                )
+            })?;
+
+            let mut res = format!("CHECK ({})", expression);
+            if detail.is_no_inherit {
+                res.push_str(" NO INHERIT");
+            }
             Ok(Some(res))
         }
         _ => {
@@ -142,105 +1376,385 @@ fn table_constraint(item: &ir::TableConstraint<'_>) -> anyhow::Result<Option<Str
     }
 }
 
-fn create_is_non_nullable_constraint(
-    cols: &[ir::Column<'_>],
-) -> impl Fn(&ir::TableConstraint<'_>) -> bool
-where
-{
-    let non_nullable_check_constraints: btree_set::BTreeSet<_> = cols
+/// the name postgres would give the constraint if it was created without one,
+/// numbered if the plain name is among `constraint_names`. a check constraint
+/// is named after the column its expression uses, if it uses just one.
+///
+/// postgres also numbers the name when a relation or a constraint of another
+/// table has it, but those are not necessarily created before this one when
+/// the output is run, so such a name is written out.
+fn default_constraint_name(
+    item: &ir::TableConstraint<'_>,
+    constraint_names: &[&str],
+) -> Option<String> {
+    let column_names = || {
+        let column_names: Vec<_> = key_columns(item)
+            .iter()
+            .map(|c| c.column_name.as_str())
+            .collect();
+        index_name_addition(&column_names)
+    };
+    let (column_names, label) = match item.constraint_type.as_str() {
+        "PRIMARY KEY" => (None, "pkey"),
+        "UNIQUE" => (Some(column_names()), "key"),
+        "FOREIGN KEY" => (Some(column_names()), "fkey"),
+        "CHECK" => (item.detail?.check_column.clone(), "check"),
+        _ => return None,
+    };
+
+    Some(default_name(
+        &item.constraint_name,
+        &item.table_name,
+        column_names.as_deref(),
+        label,
+        constraint_names,
+    ))
+}
+
+/// mirrors postgres' ChooseConstraintName and ChooseRelationName: the first of
+/// `<name1>_<name2>_<label>`, `<name1>_<name2>_<label>1`, ... that is not
+/// taken. `name` itself never counts as taken, it is the object the name is
+/// being chosen for.
+fn default_name(
+    name: &str,
+    name1: &str,
+    name2: Option<&str>,
+    label: &str,
+    taken: &[&str],
+) -> String {
+    let mut suffix = 0;
+    loop {
+        let label = match suffix {
+            0 => label.to_owned(),
+            _ => format!("{}{}", label, suffix),
+        };
+        let candidate = default_object_name(name1, name2, &label);
+        if candidate == name || !taken.contains(&candidate.as_str()) {
+            return candidate;
+        }
+        suffix += 1;
+    }
+}
+
+/// mirrors postgres' ChooseIndexNameAddition, which numbers repeated columns
+fn index_name_addition(column_names: &[&str]) -> String {
+    let mut names: Vec<String> = vec![];
+    for column_name in column_names {
+        let mut name = column_name.to_string();
+        let mut suffix = 0;
+        while names.contains(&name) {
+            suffix += 1;
+            name = format!("{}{}", column_name, suffix);
+        }
+        names.push(name);
+    }
+    join(names.iter(), "_")
+}
+
+/// the constraint's own columns in the order they appear in the constraint
+fn key_columns<'a, 'b>(item: &'b ir::TableConstraint<'a>) -> Vec<&'b ir::KeyColumn<'a>> {
+    let mut key_columns: Vec<_> = item.key_columns.iter().collect();
+    key_columns.sort_by_key(|c| c.usage.ordinal_position);
+    key_columns
+}
+
+/// information_schema reports every NOT NULL column as a check constraint, but
+/// those have no pg_constraint row of their own
+fn is_implicit_not_null(constraint: &ir::TableConstraint<'_>) -> bool {
+    constraint.constraint_type == "CHECK" && constraint.detail.is_none()
+}
+
+/// constraints that come with a parent table, unless they were also declared
+/// on the table itself
+/// constraints the table gets from its parents as it is created
+fn inherited_constraint_names<'a>(table: &'a ir::Table<'_>) -> Vec<&'a str> {
+    let inherited = table
+        .table_constraints
         .iter()
-        .filter_map(|c| match non_nullable_check_constraint(c) {
-            Ok(c) => c,
-            Err(err) => {
-                log::warn!("expected_check_constraint failed {}", err);
-                None
-            }
+        .filter(|c| !is_implicit_not_null(c) && is_inherited(c))
+        .map(|c| c.constraint_name.as_str());
+    let inherited_exclusion = table
+        .exclusion_constraints
+        .iter()
+        .filter(|c| !c.is_local)
+        .map(|c| c.constraint_name.as_str());
+    inherited.chain(inherited_exclusion).collect()
+}
+
+fn is_inherited(constraint: &ir::TableConstraint<'_>) -> bool {
+    constraint.detail.is_some_and(|d| !d.is_local)
+}
+
+fn is_local_column(col: &ir::Column<'_>) -> bool {
+    col.attribute.is_none_or(|a| a.is_local)
+}
+
+/// the `WITH OPTIONS` a partition adds to a column it gets from its parent, if
+/// any
+fn partition_column(
+    col: &ir::Column<'_>,
+    parent: &ir::TableParent<'_>,
+) -> anyhow::Result<Option<String>> {
+    let parent_col = parent
+        .columns
+        .iter()
+        .find(|c| c.column_name == col.column_name)
+        .ok_or_else(|| {
+            anyhow!(
+                "cannot find column {} in parent table {}",
+                col.column_name,
+                parent.parent_name
+            )
+        })?;
+
+    let mut res = format!("{} WITH OPTIONS", identifier(&col.column_name));
+    let mut has_options = false;
+    if col.is_not_null() && !parent_col.is_not_null() {
+        res.push_str(" NOT NULL");
+        has_options = true;
+    }
+    if let Some(default) = col.column_default.as_ref() {
+        if parent_col.column_default.as_ref() != Some(default) {
+            write!(&mut res, " DEFAULT {}", default)?;
+            has_options = true;
+        }
+    }
+
+    Ok(has_options.then_some(res))
+}
+
+fn is_validated(constraint: &ir::TableConstraint<'_>) -> bool {
+    constraint.detail.is_none_or(|d| d.is_validated)
+}
+
+fn is_added_later(constraint: &ir::TableConstraint<'_>) -> bool {
+    constraint.constraint_type == "FOREIGN KEY" || !is_validated(constraint)
+}
+
+/// the `OPTIONS (...)` clause of foreign data wrappers, servers, user mappings,
+/// foreign tables and their columns
+fn generic_options<'b>(
+    options: impl Iterator<Item = (&'b str, Option<&'b str>)>,
+) -> Option<String> {
+    let options: Vec<_> = options
+        .map(|(name, value)| {
+            format!(
+                "{} {}",
+                identifier(name),
+                literal(value.unwrap_or_default())
+            )
         })
         .collect();
-
-    if log::log_enabled!(log::Level::Info) {
-        log::info!(
-            "valid non nullable check clauses {:?}",
-            &non_nullable_check_constraints
-        )
+    if options.is_empty() {
+        return None;
     }
 
-    return move |constraint: &ir::TableConstraint<'_>| -> bool {
-        if constraint.constraint_type != "CHECK" || constraint.check_constraints.is_empty() {
-            return false;
+    Some(format!("OPTIONS ({})", join(options.iter(), ", ")))
+}
+
+fn column(col: &ir::Column<'_>) -> anyhow::Result<String> {
+    let mut res = identifier(&col.column_name);
+
+    let dimensions = col.attribute.map_or(1, |a| a.array_dimensions);
+    let serial_type = serial_type(col);
+    let data_type = match (serial_type, col.domain_name.as_ref()) {
+        (Some(serial_type), _) => serial_type.to_owned(),
+        (None, Some(domain_name)) => {
+            qualified_identifier(col.domain_schema.as_deref(), domain_name)
         }
-        constraint.check_constraints.iter().all(|c| {
-            if !non_nullable_check_constraints.contains(&c.check_clause) {
-                log::warn!(
-                    "check clause {:?} is not a nullable check clause",
-                    c.check_clause
-                )
-            }
-            true
-        })
+        (None, None) => data_type(col.column, col.array_element().as_ref(), dimensions)?,
     };
+
+    write!(&mut res, " {}", data_type)?;
+    let column_options = col
+        .options
+        .iter()
+        .map(|o| (o.option_name.as_str(), o.option_value.as_deref()));
+    if let Some(column_options) = generic_options(column_options) {
+        write!(&mut res, " {}", column_options)?;
+    }
+    let has_default_collation = col.attribute.is_some_and(|a| a.has_default_collation);
+    if let (false, Some(collation)) = (has_default_collation, col.collation_name.as_ref()) {
+        write!(
+            &mut res,
+            " COLLATE {}",
+            qualified_identifier(col.collation_schema.as_deref(), collation)
+        )?;
+    }
+    if col.is_not_null() {
+        write!(&mut res, " NOT NULL")?;
+    }
+    if let Some(identity) = identity(col)? {
+        write!(&mut res, " {}", identity)?;
+    }
+
+    // a generated column can never have a default as well
+    let is_generated = col.is_generated.as_deref() == Some("ALWAYS");
+    if is_generated {
+        let expr = col
+            .generation_expression
+            .as_ref()
+            .ok_or_else(|| anyhow!("missing expression for generated column"))?;
+        write!(&mut res, " GENERATED ALWAYS AS ({}) STORED", expr)?;
+    }
+
+    let is_serial = serial_type.is_some();
+    if let (false, false, Some(expr)) = (is_serial, is_generated, col.column_default.as_ref()) {
+        write!(&mut res, " DEFAULT {}", expr)?
+    };
+
+    Ok(res)
 }
 
-fn non_nullable_check_constraint(col: &ir::Column<'_>) -> anyhow::Result<Option<String>> {
-    let is_nullable = col.is_nullable.map_or(true, |x| x.is_yes());
-    let res = if !is_nullable {
-        Some(format!("{} IS NOT NULL", &col.column_name))
+fn identity(col: &ir::Column<'_>) -> anyhow::Result<Option<String>> {
+    if !col.is_identity.unwrap_or_default().is_yes() {
+        return Ok(None);
+    }
+
+    let generation = col
+        .identity_generation
+        .as_ref()
+        .ok_or_else(|| anyhow!("missing generation for identity column"))?;
+    let parse = |name: &str, value: Option<&String>| -> anyhow::Result<i64> {
+        value
+            .ok_or_else(|| anyhow!("missing identity {}", name))?
+            .parse()
+            .with_context(|| format!("invalid identity {}", name))
+    };
+    let start = parse("start", col.identity_start.as_ref())?;
+    let increment = parse("increment", col.identity_increment.as_ref())?;
+    let minimum = parse("minimum", col.identity_minimum.as_ref())?;
+    let maximum = parse("maximum", col.identity_maximum.as_ref())?;
+    let cycle = col.identity_cycle.unwrap_or_default().is_yes();
+
+    let (type_minimum, type_maximum) = match col.data_type.as_str() {
+        "smallint" => (i16::MIN as i64, i16::MAX as i64),
+        "integer" => (i32::MIN as i64, i32::MAX as i64),
+        _ => (i64::MIN, i64::MAX),
+    };
+    // descending sequences count down from -1 by default
+    let (default_minimum, default_maximum) = if increment > 0 {
+        (1, type_maximum)
     } else {
-        None
+        (type_minimum, -1)
     };
+    let default_start = if increment > 0 { minimum } else { maximum };
 
-    Ok(res)
+    let mut options = vec![];
+    if let Some(sequence) = col.owned_sequence {
+        let default_name = default_object_name(&col.table_name, Some(&col.column_name), "seq");
+        if sequence.sequence_name != default_name {
+            options.push(format!(
+                "SEQUENCE NAME {}",
+                table_identifier(Some(&sequence.sequence_schema), &sequence.sequence_name)
+            ));
+        }
+    }
+    if start != default_start {
+        options.push(format!("START WITH {}", start));
+    }
+    if increment != 1 {
+        options.push(format!("INCREMENT BY {}", increment));
+    }
+    if minimum != default_minimum {
+        options.push(format!("MINVALUE {}", minimum));
+    }
+    if maximum != default_maximum {
+        options.push(format!("MAXVALUE {}", maximum));
+    }
+    if let Some(sequence) = col.owned_sequence.filter(|s| s.cache_size != 1) {
+        options.push(format!("CACHE {}", sequence.cache_size));
+    }
+    if cycle {
+        options.push("CYCLE".to_owned());
+    }
+
+    let mut res = format!("GENERATED {} AS IDENTITY", generation);
+    if !options.is_empty() {
+        write!(&mut res, " ({})", join(options.iter(), " "))?;
+    }
+
+    Ok(Some(res))
 }
 
-fn column(col: &ir::Column<'_>) -> anyhow::Result<String> {
-    let mut res = identifier(&col.column_name);
+/// datetime types default to microsecond precision, so `timestamp` and
+/// `timestamp(6)` are the same type.
+const DEFAULT_DATETIME_PRECISION: i32 = 6;
 
-    let data_type = match col.data_type.as_str() {
-        "USER-DEFINED" => Err(anyhow!("unimplmented data type"))?,
-        "ARRAY" => {
-            let element_type = col
-                .element_type
-                .ok_or_else(|| anyhow!("missing element type for array"))?;
+fn data_type<T, E>(ty: &T, element_type: Option<&E>, dimensions: i32) -> anyhow::Result<String>
+where
+    T: schema::DataType,
+    E: schema::DataType,
+{
+    let data_type = ty.data_type().ok_or_else(|| anyhow!("missing data type"))?;
+    let datetime_precision = ty
+        .datetime_precision()
+        .filter(|p| *p != DEFAULT_DATETIME_PRECISION);
 
-            let data_type = element_type
-                .data_type
-                .as_ref()
-                .ok_or_else(|| anyhow!("missing data type for array"))?;
+    let res = match data_type {
+        "USER-DEFINED" => {
+            let udt_name = ty
+                .udt_name()
+                .ok_or_else(|| anyhow!("missing type name for user defined type"))?;
+
+            qualified_identifier(ty.udt_schema(), udt_name)
+        }
+        "ARRAY" => {
+            let element_type =
+                element_type.ok_or_else(|| anyhow!("missing element type for array"))?;
 
-            format!("[]{}", data_type)
+            // postgres does not enforce the declared number of dimensions, but
+            // keeps it around so int[][] still reads as int[][].
+            format!(
+                "{}{}",
+                self::data_type::<E, E>(element_type, None, 0)?,
+                "[]".repeat(dimensions.max(1) as usize)
+            )
         }
-        "character" => match col.character_maximum_length {
+        "character" => match ty.character_maximum_length() {
             Some(max) => format!("char({})", max),
             None => "char".to_string(),
         },
-        "character varying" => match col.character_maximum_length {
+        "character varying" => match ty.character_maximum_length() {
             Some(max) => format!("varchar({})", max),
             None => "varchar".to_string(),
         },
-        "integer" => match col.column_default.as_ref() {
-            Some(default) if is_serial_expression(&col.table_name, &col.column_name, default) => {
-                "serial".to_owned()
+        "bit" => match ty.character_maximum_length() {
+            Some(max) => format!("bit({})", max),
+            None => "bit".to_string(),
+        },
+        "bit varying" => match ty.character_maximum_length() {
+            Some(max) => format!("varbit({})", max),
+            None => "varbit".to_string(),
+        },
+        "numeric" => match (ty.numeric_precision(), ty.numeric_scale()) {
+            (Some(precision), Some(scale)) => format!("numeric({},{})", precision, scale),
+            (Some(precision), None) => format!("numeric({})", precision),
+            _ => "numeric".to_string(),
+        },
+        "timestamp without time zone"
+        | "timestamp with time zone"
+        | "time without time zone"
+        | "time with time zone" => match (datetime_precision, data_type.split_once(' ')) {
+            // the precision goes right after the type name: timestamp(3) with time zone
+            (Some(precision), Some((name, zone))) => {
+                format!("{}({}) {}", name, precision, zone)
             }
-            _ => "integer".to_owned(),
+            _ => data_type.to_owned(),
+        },
+        // the interval fields already carry the seconds precision, e.g. DAY TO SECOND(3)
+        "interval" => match (ty.interval_type(), datetime_precision) {
+            (Some(fields), _) => format!("interval {}", fields),
+            (None, Some(precision)) => format!("interval({})", precision),
+            (None, None) => "interval".to_string(),
         },
         data_type => data_type.to_owned(),
     };
 
-    write!(&mut res, " {}", data_type)?;
-    if col.is_nullable.unwrap_or_default().is_no() {
-        write!(&mut res, " NOT NULL")?;
-    }
-
-    let is_serial = data_type == "serial";
-    match (is_serial, col.column_default.as_ref()) {
-        (false, Some(expr)) => write!(&mut res, " DEFAULT {}", expr)?,
-        _ => {}
-    };
-
     Ok(res)
 }
 
-fn join<'a, I, S>(iter: I, sep: &'a str) -> String
+fn join<I, S>(iter: I, sep: &str) -> String
 where
     I: Iterator<Item = S>,
     S: AsRef<str>,
@@ -4,7 +4,30 @@ use crate::schema;
 
 #[derive(Debug, Clone)]
 pub struct All<'a> {
+    pub schemas: Rc<Vec<Schema<'a>>>,
+    /// sorted so that every extension comes after the extensions it requires.
+    pub extensions: Rc<Vec<Extension<'a>>>,
+    pub collations: Rc<Vec<Collation<'a>>>,
+    pub foreign_data_wrappers: Rc<Vec<ForeignDataWrapper<'a>>>,
+    pub foreign_servers: Rc<Vec<ForeignServer<'a>>>,
+    pub enums: Rc<Vec<Enum<'a>>>,
+    /// domains, composite types, and the functions, procedures and window
+    /// functions that can be created before the tables, sorted so that every
+    /// one of them comes after the ones it is built from.
+    pub types_and_routines: Rc<Vec<TypeOrRoutine<'a>>>,
+    pub sequences: Rc<Vec<Sequence<'a>>>,
+    /// functions, procedures and window functions whose arguments or result
+    /// use the row type of a table or view, so they wait for the tables.
+    pub routines: Rc<Vec<Routine<'a>>>,
+    pub aggregates: Rc<Vec<Aggregate<'a>>>,
+    /// sorted so that every table comes after the tables it inherits from.
     pub tables: Rc<Vec<Table<'a>>>,
+    /// views and materialized views, sorted so that every one of them comes
+    /// after the ones it selects from.
+    pub views: Rc<Vec<AnyView<'a>>>,
+    /// with an object's revokes before its grants
+    pub privileges: Rc<Vec<Privilege<'a>>>,
+    pub default_privileges: Rc<Vec<DefaultPrivilege<'a>>>,
 }
 
 #[derive(Debug, Clone)]
@@ -12,6 +35,205 @@ pub struct Table<'a> {
     pub table: &'a schema::Table,
     pub table_constraints: Rc<Vec<TableConstraint<'a>>>,
     pub columns: Rc<Vec<Column<'a>>>,
+    pub indexes: Rc<Vec<Index<'a>>>,
+    pub exclusion_constraints: Rc<Vec<ExclusionConstraint<'a>>>,
+    pub triggers: Rc<Vec<Trigger<'a>>>,
+    /// set if the table is declared with PARTITION BY
+    pub partitioning: Option<&'a schema::PartitionedTable>,
+    /// in inheritance order
+    pub parents: Rc<Vec<TableParent<'a>>>,
+    /// set for tables of type FOREIGN
+    pub foreign_table: Option<ForeignTable<'a>>,
+    /// set if row level security is enabled or forced
+    pub row_security: Option<&'a schema::RowSecurity>,
+    pub policies: Rc<Vec<&'a schema::Policy>>,
+    /// set with COMMENT ON
+    pub comment: Option<&'a str>,
+}
+
+impl<'a> Table<'a> {
+    /// the parent of a table created with PARTITION OF
+    pub fn partition_parent(&self) -> Option<&TableParent<'a>> {
+        self.parents.iter().find(|p| p.partition_bound.is_some())
+    }
+}
+
+/// the privileges that one GRANT or REVOKE statement hands out or takes away
+#[derive(Debug, Clone)]
+pub struct Privilege<'a> {
+    /// the object, the grantee and the kind of statement are the same for all of them
+    pub privilege: &'a schema::Privilege,
+    pub privilege_types: Vec<&'a str>,
+}
+
+impl<'a> std::ops::Deref for Privilege<'a> {
+    type Target = &'a schema::Privilege;
+
+    fn deref(&self) -> &Self::Target {
+        &self.privilege
+    }
+}
+
+/// the privileges that one ALTER DEFAULT PRIVILEGES statement hands out or takes away
+#[derive(Debug, Clone)]
+pub struct DefaultPrivilege<'a> {
+    /// the role, the schema, the objects, the grantee and the kind of statement are the same for all of them
+    pub default_privilege: &'a schema::DefaultPrivilege,
+    pub privilege_types: Vec<&'a str>,
+}
+
+impl<'a> std::ops::Deref for DefaultPrivilege<'a> {
+    type Target = &'a schema::DefaultPrivilege;
+
+    fn deref(&self) -> &Self::Target {
+        &self.default_privilege
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Schema<'a> {
+    pub schema: &'a schema::Schema,
+}
+
+impl<'a> std::ops::Deref for Schema<'a> {
+    type Target = &'a schema::Schema;
+
+    fn deref(&self) -> &Self::Target {
+        &self.schema
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Extension<'a> {
+    pub extension: &'a schema::Extension,
+}
+
+impl<'a> std::ops::Deref for Extension<'a> {
+    type Target = &'a schema::Extension;
+
+    fn deref(&self) -> &Self::Target {
+        &self.extension
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Routine<'a> {
+    pub routine: &'a schema::Routine,
+    pub type_usage: Rc<Vec<&'a schema::RoutineTypeUsage>>,
+    pub comment: Option<&'a str>,
+}
+
+impl<'a> std::ops::Deref for Routine<'a> {
+    type Target = &'a schema::Routine;
+
+    fn deref(&self) -> &Self::Target {
+        &self.routine
+    }
+}
+
+impl<'a> Routine<'a> {
+    pub fn uses_relation_row_type(&self) -> bool {
+        self.type_usage.iter().any(|u| u.is_relation_row_type)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Aggregate<'a> {
+    pub aggregate: &'a schema::Aggregate,
+    pub comment: Option<&'a str>,
+}
+
+impl<'a> std::ops::Deref for Aggregate<'a> {
+    type Target = &'a schema::Aggregate;
+
+    fn deref(&self) -> &Self::Target {
+        &self.aggregate
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Collation<'a> {
+    pub collation: &'a schema::Collation,
+}
+
+impl<'a> std::ops::Deref for Collation<'a> {
+    type Target = &'a schema::Collation;
+
+    fn deref(&self) -> &Self::Target {
+        &self.collation
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ForeignTable<'a> {
+    pub foreign_table: &'a schema::ForeignTable,
+    pub options: Rc<Vec<&'a schema::ForeignTableOption>>,
+}
+
+impl<'a> std::ops::Deref for ForeignTable<'a> {
+    type Target = &'a schema::ForeignTable;
+
+    fn deref(&self) -> &Self::Target {
+        &self.foreign_table
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ForeignDataWrapper<'a> {
+    pub foreign_data_wrapper: &'a schema::ForeignDataWrapper,
+    pub options: Rc<Vec<&'a schema::ForeignDataWrapperOption>>,
+}
+
+impl<'a> std::ops::Deref for ForeignDataWrapper<'a> {
+    type Target = &'a schema::ForeignDataWrapper;
+
+    fn deref(&self) -> &Self::Target {
+        &self.foreign_data_wrapper
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ForeignServer<'a> {
+    pub foreign_server: &'a schema::ForeignServer,
+    pub options: Rc<Vec<&'a schema::ForeignServerOption>>,
+    pub user_mappings: Rc<Vec<UserMapping<'a>>>,
+}
+
+impl<'a> std::ops::Deref for ForeignServer<'a> {
+    type Target = &'a schema::ForeignServer;
+
+    fn deref(&self) -> &Self::Target {
+        &self.foreign_server
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UserMapping<'a> {
+    pub user_mapping: &'a schema::UserMapping,
+    pub options: Rc<Vec<&'a schema::UserMappingOption>>,
+}
+
+impl<'a> std::ops::Deref for UserMapping<'a> {
+    type Target = &'a schema::UserMapping;
+
+    fn deref(&self) -> &Self::Target {
+        &self.user_mapping
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TableParent<'a> {
+    pub inheritance: &'a schema::TableInheritance,
+    /// columns of the parent table
+    pub columns: Rc<Vec<Column<'a>>>,
+}
+
+impl<'a> std::ops::Deref for TableParent<'a> {
+    type Target = &'a schema::TableInheritance;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inheritance
+    }
 }
 
 impl<'a> std::ops::Deref for Table<'a> {
@@ -22,6 +244,312 @@ impl<'a> std::ops::Deref for Table<'a> {
     }
 }
 
+/// the element type of an array column. information_schema.element_types
+/// drops the type modifiers of the elements, so they come from the column's
+/// pg_attribute row instead.
+#[derive(Debug, Clone, Copy)]
+pub struct ArrayElement<'a> {
+    pub element_type: &'a schema::ElementType,
+    pub attribute: Option<&'a schema::ColumnAttribute>,
+}
+
+impl<'a> schema::DataType for ArrayElement<'a> {
+    fn data_type(&self) -> Option<&str> {
+        self.element_type.data_type.as_deref()
+    }
+
+    fn character_maximum_length(&self) -> Option<i32> {
+        self.attribute
+            .and_then(|a| a.element_character_maximum_length)
+    }
+
+    fn numeric_precision(&self) -> Option<i32> {
+        self.attribute.and_then(|a| a.element_numeric_precision)
+    }
+
+    fn numeric_scale(&self) -> Option<i32> {
+        self.attribute.and_then(|a| a.element_numeric_scale)
+    }
+
+    fn datetime_precision(&self) -> Option<i32> {
+        self.attribute.and_then(|a| a.element_datetime_precision)
+    }
+
+    fn interval_type(&self) -> Option<&str> {
+        self.attribute
+            .and_then(|a| a.element_interval_type.as_deref())
+    }
+
+    fn udt_schema(&self) -> Option<&str> {
+        self.element_type.udt_schema.as_deref()
+    }
+
+    fn udt_name(&self) -> Option<&str> {
+        self.element_type.udt_name.as_deref()
+    }
+}
+
+impl<'a> Column<'a> {
+    /// whether the column itself is declared NOT NULL. information_schema
+    /// also reports columns of a NOT NULL domain as not nullable.
+    pub fn is_not_null(&self) -> bool {
+        match self.attribute {
+            Some(attribute) => attribute.is_not_null,
+            None => self.is_nullable.unwrap_or_default().is_no(),
+        }
+    }
+
+    pub fn array_element(&self) -> Option<ArrayElement<'a>> {
+        self.element_type.map(|element_type| ArrayElement {
+            element_type,
+            attribute: self.attribute,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Enum<'a> {
+    pub enum_type: &'a schema::Enum,
+    pub labels: Rc<Vec<&'a schema::EnumLabel>>,
+    pub comment: Option<&'a str>,
+}
+
+impl<'a> std::ops::Deref for Enum<'a> {
+    type Target = &'a schema::Enum;
+
+    fn deref(&self) -> &Self::Target {
+        &self.enum_type
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CompositeType<'a> {
+    pub composite_type: &'a schema::CompositeType,
+    pub attributes: Rc<Vec<&'a schema::CompositeTypeAttribute>>,
+    pub comment: Option<&'a str>,
+}
+
+impl<'a> std::ops::Deref for CompositeType<'a> {
+    type Target = &'a schema::CompositeType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.composite_type
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Domain<'a> {
+    pub domain: &'a schema::Domain,
+    pub element_type: Option<&'a schema::ElementType>,
+    pub constraints: Rc<Vec<DomainConstraint<'a>>>,
+    /// routines its default and check constraints call
+    pub routine_usage: Rc<Vec<&'a schema::DomainRoutineUsage>>,
+    pub comment: Option<&'a str>,
+}
+
+impl<'a> std::ops::Deref for Domain<'a> {
+    type Target = &'a schema::Domain;
+
+    fn deref(&self) -> &Self::Target {
+        &self.domain
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DomainConstraint<'a> {
+    pub domain_constraint: &'a schema::DomainConstraint,
+    pub check_constraints: Rc<Vec<CheckConstraint<'a>>>,
+    pub comment: Option<&'a str>,
+}
+
+impl<'a> std::ops::Deref for DomainConstraint<'a> {
+    type Target = &'a schema::DomainConstraint;
+
+    fn deref(&self) -> &Self::Target {
+        &self.domain_constraint
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Sequence<'a> {
+    pub sequence: &'a schema::Sequence,
+    pub owner: Option<Column<'a>>,
+}
+
+impl<'a> std::ops::Deref for Sequence<'a> {
+    type Target = &'a schema::Sequence;
+
+    fn deref(&self) -> &Self::Target {
+        &self.sequence
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct View<'a> {
+    pub view: &'a schema::View,
+    pub columns: Rc<Vec<Column<'a>>>,
+    pub table_usage: Rc<Vec<&'a schema::ViewTableUsage>>,
+    pub materialized_view_usage: Rc<Vec<&'a schema::MaterializedViewUsage>>,
+    pub comment: Option<&'a str>,
+}
+
+impl<'a> std::ops::Deref for View<'a> {
+    type Target = &'a schema::View;
+
+    fn deref(&self) -> &Self::Target {
+        &self.view
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MaterializedView<'a> {
+    pub materialized_view: &'a schema::MaterializedView,
+    pub table_usage: Rc<Vec<&'a schema::MaterializedViewUsage>>,
+    pub indexes: Rc<Vec<Index<'a>>>,
+    pub comment: Option<&'a str>,
+    /// set with COMMENT ON COLUMN. information_schema leaves out the columns
+    /// of materialized views, so these are kept on their own.
+    pub column_comments: Rc<Vec<&'a schema::Comment>>,
+}
+
+impl<'a> std::ops::Deref for MaterializedView<'a> {
+    type Target = &'a schema::MaterializedView;
+
+    fn deref(&self) -> &Self::Target {
+        &self.materialized_view
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum AnyView<'a> {
+    View(View<'a>),
+    MaterializedView(MaterializedView<'a>),
+}
+
+impl<'a> AnyView<'a> {
+    fn key(&self) -> (Option<&'a str>, Option<&'a str>) {
+        match self {
+            AnyView::View(v) => (v.view.table_schema.as_deref(), v.view.table_name.as_deref()),
+            AnyView::MaterializedView(m) => (
+                Some(m.materialized_view.schemaname.as_str()),
+                Some(m.materialized_view.matviewname.as_str()),
+            ),
+        }
+    }
+
+    fn dependencies(&self) -> Vec<(Option<&'a str>, Option<&'a str>)> {
+        let materialized_view_usage = match self {
+            AnyView::View(v) => &v.materialized_view_usage,
+            AnyView::MaterializedView(m) => &m.table_usage,
+        };
+        let materialized_view_usage = materialized_view_usage
+            .iter()
+            .map(|u| (Some(u.table_schema.as_str()), Some(u.table_name.as_str())));
+
+        match self {
+            AnyView::View(v) => v
+                .table_usage
+                .iter()
+                .map(|u| (u.table_schema.as_deref(), Some(u.table_name.as_str())))
+                .chain(materialized_view_usage)
+                .collect(),
+            AnyView::MaterializedView(_) => materialized_view_usage.collect(),
+        }
+    }
+
+    pub fn is_system_schema(&self) -> bool {
+        match self {
+            AnyView::View(v) => v.is_system_schema(),
+            AnyView::MaterializedView(m) => m.is_system_schema(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum TypeOrRoutine<'a> {
+    Domain(Domain<'a>),
+    CompositeType(CompositeType<'a>),
+    Routine(Routine<'a>),
+}
+
+impl<'a> TypeOrRoutine<'a> {
+    /// schema, name, and for routines the identity arguments
+    fn key(&self) -> (Option<&'a str>, Option<&'a str>, Option<&'a str>) {
+        match self {
+            TypeOrRoutine::Domain(d) => (
+                d.domain.domain_schema.as_deref(),
+                d.domain.domain_name.as_deref(),
+                None,
+            ),
+            TypeOrRoutine::CompositeType(t) => (
+                Some(t.composite_type.type_schema.as_str()),
+                Some(t.composite_type.type_name.as_str()),
+                None,
+            ),
+            TypeOrRoutine::Routine(r) => (
+                Some(r.routine.routine_schema.as_str()),
+                Some(r.routine.routine_name.as_str()),
+                Some(r.routine.identity_arguments.as_str()),
+            ),
+        }
+    }
+
+    fn dependencies(&self) -> Vec<(Option<&'a str>, Option<&'a str>, Option<&'a str>)> {
+        match self {
+            TypeOrRoutine::Domain(d) => {
+                let element_udt = d
+                    .element_type
+                    .map(|e| (e.udt_schema.as_deref(), e.udt_name.as_deref(), None));
+                let routines = d.routine_usage.iter().map(|u| {
+                    (
+                        Some(u.routine_schema.as_str()),
+                        Some(u.routine_name.as_str()),
+                        Some(u.identity_arguments.as_str()),
+                    )
+                });
+                std::iter::once((
+                    d.domain.udt_schema.as_deref(),
+                    d.domain.udt_name.as_deref(),
+                    None,
+                ))
+                .chain(element_udt)
+                .chain(routines)
+                .collect()
+            }
+            TypeOrRoutine::CompositeType(t) => t
+                .attributes
+                .iter()
+                .map(|a| {
+                    (
+                        Some(a.attribute_type_schema.as_str()),
+                        Some(a.attribute_type_name.as_str()),
+                        None,
+                    )
+                })
+                .collect(),
+            TypeOrRoutine::Routine(r) => r
+                .type_usage
+                .iter()
+                .map(|u| {
+                    (
+                        Some(u.type_schema.as_str()),
+                        Some(u.type_name.as_str()),
+                        None,
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    pub fn is_system_schema(&self) -> bool {
+        match self {
+            TypeOrRoutine::Domain(d) => d.is_system_schema(),
+            TypeOrRoutine::CompositeType(t) => t.is_system_schema(),
+            TypeOrRoutine::Routine(r) => r.is_system_schema(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CheckConstraint<'a> {
     pub check_constraint: &'a schema::CheckConstraint,
@@ -53,6 +581,11 @@ impl<'a> std::ops::Deref for KeyColumn<'a> {
 pub struct Column<'a> {
     pub column: &'a schema::Column,
     pub element_type: Option<&'a schema::ElementType>,
+    pub attribute: Option<&'a schema::ColumnAttribute>,
+    pub owned_sequence: Option<&'a schema::Sequence>,
+    /// only foreign table columns have options
+    pub options: Rc<Vec<&'a schema::ColumnOption>>,
+    pub comment: Option<&'a str>,
 }
 
 impl<'a> std::ops::Deref for Column<'a> {
@@ -63,14 +596,61 @@ impl<'a> std::ops::Deref for Column<'a> {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct Index<'a> {
+    pub index: &'a schema::Index,
+    pub columns: Rc<Vec<&'a schema::IndexColumn>>,
+}
+
+impl<'a> std::ops::Deref for Index<'a> {
+    type Target = &'a schema::Index;
+
+    fn deref(&self) -> &Self::Target {
+        &self.index
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Trigger<'a> {
+    pub trigger: &'a schema::Trigger,
+}
+
+impl<'a> std::ops::Deref for Trigger<'a> {
+    type Target = &'a schema::Trigger;
+
+    fn deref(&self) -> &Self::Target {
+        &self.trigger
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TableConstraint<'a> {
     pub table_constraint: &'a schema::TableConstraint,
-    pub check_constraints: Rc<Vec<CheckConstraint<'a>>>,
-    pub columns: Rc<Vec<Column<'a>>>,
+    /// none for the NOT NULL checks information_schema makes up for non-nullable columns
+    pub detail: Option<&'a schema::ConstraintDetail>,
+    /// only set for foreign keys
+    pub referential_constraint: Option<&'a schema::ReferentialConstraint>,
     pub key_columns: Rc<Vec<KeyColumn<'a>>>,
     // should always be just one table
     pub tables: Rc<Vec<&'a schema::Table>>, //referencing the schema (not the ir::Table) since using the ir table would cause a circular reference.
+    pub comment: Option<&'a str>,
+}
+
+/// information_schema leaves exclusion constraints out, so they are pieced
+/// together from pg_constraint and the index backing them.
+#[derive(Debug, Clone)]
+pub struct ExclusionConstraint<'a> {
+    pub detail: &'a schema::ConstraintDetail,
+    pub index: Index<'a>,
+    pub comment: Option<&'a str>,
+}
+
+impl<'a> std::ops::Deref for ExclusionConstraint<'a> {
+    type Target = &'a schema::ConstraintDetail;
+
+    fn deref(&self) -> &Self::Target {
+        &self.detail
+    }
 }
 
 impl<'a> std::ops::Deref for TableConstraint<'a> {
@@ -85,6 +665,9 @@ fn get_all_tables<'a>(
     all: &'a schema::All,
     columns: &[Column<'a>],
     table_constraints: &[TableConstraint<'a>],
+    indexes: &[Index<'a>],
+    exclusion_constraints: &[ExclusionConstraint<'a>],
+    triggers: &[Trigger<'a>],
 ) -> Vec<Table<'a>> {
     let columns_by_table = collect_by_key(columns.iter(), |c| {
         (c.column.table_schema.as_ref(), &c.column.table_name)
@@ -95,36 +678,491 @@ fn get_all_tables<'a>(
             &c.table_constraint.table_name,
         )
     });
+    let indexes_by_table = collect_by_key(indexes.iter(), |i| {
+        (Some(&i.index.table_schema), &i.index.table_name)
+    });
+    let exclusion_constraints_by_table = collect_by_key(exclusion_constraints.iter(), |c| {
+        (Some(&c.detail.table_schema), &c.detail.table_name)
+    });
+    let partitioned_tables_by_table = collect_by_key(all.partitioned_tables.iter(), |p| {
+        (Some(&p.table_schema), &p.table_name)
+    });
+    let parents_by_table = collect_by_key(all.table_inheritance.iter(), |i| {
+        (Some(&i.table_schema), &i.table_name)
+    });
+    let foreign_tables_by_table = collect_by_key(all.foreign_tables.iter(), |f| {
+        (Some(&f.foreign_table_schema), &f.foreign_table_name)
+    });
+    let foreign_table_options_by_table = collect_by_key(all.foreign_table_options.iter(), |o| {
+        (&o.foreign_table_schema, &o.foreign_table_name)
+    });
+    let triggers_by_table = collect_by_key(triggers.iter(), |t| {
+        (
+            Some(&t.trigger.event_object_schema),
+            &t.trigger.event_object_table,
+        )
+    });
+    let row_security_by_table = collect_by_key(all.row_security.iter(), |r| {
+        (Some(&r.table_schema), &r.table_name)
+    });
+    let policies_by_table =
+        collect_by_key(all.policies.iter(), |p| (Some(&p.schemaname), &p.tablename));
+    let comments = comments_by_object(all, "relation");
 
     all.tables
         .iter()
-        .map(|table| {
-            let mut columns: Vec<_> = columns_by_table
-                .get_vec(&(table.table_schema.as_ref(), &table.table_name))
+        .map(|table| {
+            let mut columns: Vec<_> = columns_by_table
+                .get_vec(&(table.table_schema.as_ref(), &table.table_name))
+                .iter()
+                .flat_map(|v| v.iter())
+                .cloned()
+                .cloned()
+                .collect();
+
+            let mut table_constraints: Vec<_> = table_constraints_by_table
+                .get_vec(&(table.table_schema.as_ref(), &table.table_name))
+                .iter()
+                .flat_map(|v| v.iter())
+                .cloned()
+                .cloned()
+                .collect();
+
+            let mut indexes: Vec<_> = indexes_by_table
+                .get_vec(&(table.table_schema.as_ref(), &table.table_name))
+                .iter()
+                .flat_map(|v| v.iter())
+                .cloned()
+                .cloned()
+                .collect();
+
+            let mut exclusion_constraints: Vec<_> = exclusion_constraints_by_table
+                .get_vec(&(table.table_schema.as_ref(), &table.table_name))
+                .iter()
+                .flat_map(|v| v.iter())
+                .cloned()
+                .cloned()
+                .collect();
+
+            let partitioning = partitioned_tables_by_table
+                .get(&(table.table_schema.as_ref(), &table.table_name))
+                .cloned();
+
+            let mut parents: Vec<_> = parents_by_table
+                .get_vec(&(table.table_schema.as_ref(), &table.table_name))
+                .iter()
+                .flat_map(|v| v.iter())
+                .map(|inheritance| {
+                    let mut columns: Vec<_> = columns_by_table
+                        .get_vec(&(Some(&inheritance.parent_schema), &inheritance.parent_name))
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .cloned()
+                        .cloned()
+                        .collect();
+                    columns.sort_by_key(|c| c.ordinal_position);
+
+                    TableParent {
+                        inheritance,
+                        columns: Rc::new(columns),
+                    }
+                })
+                .collect();
+
+            let foreign_table = foreign_tables_by_table
+                .get(&(table.table_schema.as_ref(), &table.table_name))
+                .map(|foreign_table| ForeignTable {
+                    foreign_table,
+                    options: Rc::new(
+                        foreign_table_options_by_table
+                            .get_vec(&(
+                                &foreign_table.foreign_table_schema,
+                                &foreign_table.foreign_table_name,
+                            ))
+                            .iter()
+                            .flat_map(|v| v.iter())
+                            .cloned()
+                            .collect(),
+                    ),
+                });
+
+            let mut triggers: Vec<_> = triggers_by_table
+                .get_vec(&(table.table_schema.as_ref(), &table.table_name))
+                .iter()
+                .flat_map(|v| v.iter())
+                .cloned()
+                .cloned()
+                .collect();
+
+            let row_security = row_security_by_table
+                .get(&(table.table_schema.as_ref(), &table.table_name))
+                .cloned();
+
+            let mut policies: Vec<_> = policies_by_table
+                .get_vec(&(table.table_schema.as_ref(), &table.table_name))
+                .iter()
+                .flat_map(|v| v.iter())
+                .cloned()
+                .collect();
+
+            columns.sort_by_key(|c| c.ordinal_position);
+            table_constraints.sort_by_key(|t| &t.constraint_name);
+            indexes.sort_by_key(|i| &i.index_name);
+            exclusion_constraints.sort_by_key(|c| &c.constraint_name);
+            parents.sort_by_key(|p| p.sequence_number);
+            triggers.sort_by_key(|t| &t.trigger_name);
+            policies.sort_by_key(|p| &p.policyname);
+
+            Table {
+                table,
+                columns: Rc::new(columns),
+                table_constraints: Rc::new(table_constraints),
+                indexes: Rc::new(indexes),
+                exclusion_constraints: Rc::new(exclusion_constraints),
+                triggers: Rc::new(triggers),
+                partitioning,
+                parents: Rc::new(parents),
+                foreign_table,
+                row_security,
+                policies: Rc::new(policies),
+                comment: comments
+                    .get(&(table.table_schema.as_ref(), &table.table_name, None))
+                    .copied(),
+            }
+        })
+        .collect()
+}
+
+fn get_all_enums(all: &schema::All) -> Vec<Enum<'_>> {
+    let labels_by_enum = collect_by_key(all.enum_labels.iter(), |l| (&l.type_schema, &l.type_name));
+    let comments = comments_by_object(all, "type");
+
+    let mut enums: Vec<_> = all
+        .enums
+        .iter()
+        .map(|enum_type| {
+            let mut labels: Vec<_> = labels_by_enum
+                .get_vec(&(&enum_type.type_schema, &enum_type.type_name))
+                .iter()
+                .flat_map(|v| v.iter())
+                .cloned()
+                .collect();
+
+            labels.sort_by(|a, b| a.sort_order.total_cmp(&b.sort_order));
+
+            Enum {
+                enum_type,
+                labels: Rc::new(labels),
+                comment: comments
+                    .get(&(Some(&enum_type.type_schema), &enum_type.type_name, None))
+                    .copied(),
+            }
+        })
+        .collect();
+
+    enums.sort_by_key(|e| (&e.type_schema, &e.type_name));
+
+    enums
+}
+
+fn get_all_composite_types(all: &schema::All) -> Vec<CompositeType<'_>> {
+    let attributes_by_type = collect_by_key(all.composite_type_attributes.iter(), |a| {
+        (&a.type_schema, &a.type_name)
+    });
+    let comments = comments_by_object(all, "type");
+
+    let mut composite_types: Vec<_> = all
+        .composite_types
+        .iter()
+        .map(|composite_type| {
+            let mut attributes: Vec<_> = attributes_by_type
+                .get_vec(&(&composite_type.type_schema, &composite_type.type_name))
+                .iter()
+                .flat_map(|v| v.iter())
+                .cloned()
+                .collect();
+
+            attributes.sort_by_key(|a| a.ordinal_position);
+
+            CompositeType {
+                composite_type,
+                attributes: Rc::new(attributes),
+                comment: comments
+                    .get(&(
+                        Some(&composite_type.type_schema),
+                        &composite_type.type_name,
+                        None,
+                    ))
+                    .copied(),
+            }
+        })
+        .collect();
+
+    composite_types.sort_by_key(|t| (&t.type_schema, &t.type_name));
+
+    composite_types
+}
+
+fn get_all_domains(all: &schema::All) -> Vec<Domain<'_>> {
+    let element_types_by_domain = collect_by_key(all.element_types.iter(), |e| {
+        (
+            &e.object_schema,
+            e.object_name.as_str(),
+            e.object_type.as_str(),
+            &e.collection_type_identifier,
+        )
+    });
+    let constraints_by_domain = collect_by_key(all.domain_constraints.iter(), |c| {
+        (c.domain_schema.as_ref(), &c.domain_name)
+    });
+    let check_constraints_by_constraint = collect_by_key(all.check_constraints.iter(), |c| {
+        (c.constraint_schema.as_ref(), &c.constraint_name)
+    });
+    let routine_usage_by_domain = collect_by_key(all.domain_routine_usage.iter(), |u| {
+        (Some(&u.domain_schema), &u.domain_name)
+    });
+    let comments = comments_by_object(all, "type");
+    let constraint_comments = comments_by_object(all, "domain constraint");
+
+    let mut domains: Vec<_> = all
+        .domains
+        .iter()
+        .filter_map(|domain| {
+            let domain_name = domain.domain_name.as_ref()?;
+
+            let element_type = element_types_by_domain
+                .get(&(
+                    &domain.domain_schema,
+                    domain_name.as_str(),
+                    "DOMAIN",
+                    &domain.dtd_identifier,
+                ))
+                .cloned();
+
+            let mut constraints: Vec<_> = constraints_by_domain
+                .get_vec(&(domain.domain_schema.as_ref(), domain_name))
+                .iter()
+                .flat_map(|v| v.iter())
+                .map(|domain_constraint| {
+                    let check_constraints = check_constraints_by_constraint
+                        .get_vec(&(
+                            domain_constraint.constraint_schema.as_ref(),
+                            &domain_constraint.constraint_name,
+                        ))
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .map(|check| CheckConstraint {
+                            check_constraint: check,
+                        })
+                        .collect();
+
+                    DomainConstraint {
+                        domain_constraint,
+                        check_constraints: Rc::new(check_constraints),
+                        comment: constraint_comments
+                            .get(&(
+                                domain.domain_schema.as_ref(),
+                                domain_name,
+                                Some(&domain_constraint.constraint_name),
+                            ))
+                            .copied(),
+                    }
+                })
+                .collect();
+
+            constraints.sort_by_key(|c| &c.constraint_name);
+
+            let routine_usage: Vec<_> = routine_usage_by_domain
+                .get_vec(&(domain.domain_schema.as_ref(), domain_name))
+                .iter()
+                .flat_map(|v| v.iter())
+                .cloned()
+                .collect();
+
+            Some(Domain {
+                domain,
+                element_type,
+                constraints: Rc::new(constraints),
+                routine_usage: Rc::new(routine_usage),
+                comment: comments
+                    .get(&(domain.domain_schema.as_ref(), domain_name, None))
+                    .copied(),
+            })
+        })
+        .collect();
+
+    domains.sort_by_key(|d| (&d.domain_schema, &d.domain_name));
+
+    domains
+}
+
+fn get_all_sequences<'a>(all: &'a schema::All, columns: &[Column<'a>]) -> Vec<Sequence<'a>> {
+    let column_by_table_column = collect_by_key(columns.iter(), |c| {
+        (
+            c.column.table_schema.as_ref(),
+            &c.column.table_name,
+            &c.column.column_name,
+        )
+    });
+
+    let mut sequences: Vec<_> = all
+        .sequences
+        .iter()
+        .map(|sequence| {
+            let owner = match (
+                sequence.owned_by_table_name.as_ref(),
+                sequence.owned_by_column_name.as_ref(),
+            ) {
+                (Some(table_name), Some(column_name)) => column_by_table_column
+                    .get(&(
+                        sequence.owned_by_table_schema.as_ref(),
+                        table_name,
+                        column_name,
+                    ))
+                    .cloned()
+                    .cloned(),
+                _ => None,
+            };
+
+            Sequence { sequence, owner }
+        })
+        .collect();
+
+    sequences.sort_by_key(|s| (&s.sequence_schema, &s.sequence_name));
+
+    sequences
+}
+
+fn get_all_views<'a>(all: &'a schema::All, columns: &[Column<'a>]) -> Vec<View<'a>> {
+    let columns_by_view = collect_by_key(columns.iter(), |c| {
+        (c.column.table_schema.as_ref(), &c.column.table_name)
+    });
+    let table_usage_by_view = collect_by_key(all.view_table_usage.iter(), |u| {
+        (u.view_schema.as_ref(), &u.view_name)
+    });
+    let materialized_view_usage_by_view = collect_by_key(all.materialized_view_usage.iter(), |u| {
+        (Some(&u.view_schema), &u.view_name)
+    });
+    let comments = comments_by_object(all, "relation");
+
+    let mut views: Vec<_> = all
+        .views
+        .iter()
+        .filter_map(|view| {
+            let view_name = view.table_name.as_ref()?;
+            let key = (view.table_schema.as_ref(), view_name);
+
+            let mut columns: Vec<_> = columns_by_view
+                .get_vec(&key)
+                .iter()
+                .flat_map(|v| v.iter())
+                .cloned()
+                .cloned()
+                .collect();
+
+            let mut table_usage: Vec<_> = table_usage_by_view
+                .get_vec(&key)
+                .iter()
+                .flat_map(|v| v.iter())
+                .cloned()
+                .collect();
+
+            let mut materialized_view_usage: Vec<_> = materialized_view_usage_by_view
+                .get_vec(&key)
+                .iter()
+                .flat_map(|v| v.iter())
+                .cloned()
+                .collect();
+
+            columns.sort_by_key(|c| c.ordinal_position);
+            table_usage.sort_by_key(|u| (&u.table_schema, &u.table_name));
+            materialized_view_usage.sort_by_key(|u| (&u.table_schema, &u.table_name));
+
+            Some(View {
+                view,
+                columns: Rc::new(columns),
+                table_usage: Rc::new(table_usage),
+                materialized_view_usage: Rc::new(materialized_view_usage),
+                comment: comments
+                    .get(&(view.table_schema.as_ref(), view_name, None))
+                    .copied(),
+            })
+        })
+        .collect();
+
+    views.sort_by_key(|v| (&v.table_schema, &v.table_name));
+    views
+}
+
+fn get_all_materialized_views<'a>(
+    all: &'a schema::All,
+    indexes: &[Index<'a>],
+) -> Vec<MaterializedView<'a>> {
+    let table_usage_by_view = collect_by_key(all.materialized_view_usage.iter(), |u| {
+        (&u.view_schema, &u.view_name)
+    });
+    let indexes_by_table = collect_by_key(indexes.iter(), |i| {
+        (&i.index.table_schema, &i.index.table_name)
+    });
+    let comments = comments_by_object(all, "relation");
+    let column_comments_by_view = collect_by_key(
+        all.comments.iter().filter(|c| c.object_type == "column"),
+        |c| (&c.object_schema, &c.object_name),
+    );
+
+    let mut materialized_views: Vec<_> = all
+        .materialized_views
+        .iter()
+        .map(|materialized_view| {
+            let key = (
+                &materialized_view.schemaname,
+                &materialized_view.matviewname,
+            );
+
+            let mut table_usage: Vec<_> = table_usage_by_view
+                .get_vec(&key)
                 .iter()
                 .flat_map(|v| v.iter())
                 .cloned()
-                .cloned()
                 .collect();
 
-            let mut table_constraints: Vec<_> = table_constraints_by_table
-                .get_vec(&(table.table_schema.as_ref(), &table.table_name))
+            let mut indexes: Vec<_> = indexes_by_table
+                .get_vec(&key)
                 .iter()
                 .flat_map(|v| v.iter())
                 .cloned()
                 .cloned()
                 .collect();
 
-            columns.sort_by_key(|c| c.ordinal_position);
-            table_constraints.sort_by_key(|t| &t.constraint_name);
+            let mut column_comments: Vec<_> = column_comments_by_view
+                .get_vec(&key)
+                .iter()
+                .flat_map(|v| v.iter())
+                .cloned()
+                .collect();
 
-            Table {
-                table,
-                columns: Rc::new(columns),
-                table_constraints: Rc::new(table_constraints),
+            table_usage.sort_by_key(|u| (&u.table_schema, &u.table_name));
+            indexes.sort_by_key(|i| &i.index_name);
+            column_comments.sort_by_key(|c| &c.member_name);
+
+            MaterializedView {
+                materialized_view,
+                table_usage: Rc::new(table_usage),
+                indexes: Rc::new(indexes),
+                column_comments: Rc::new(column_comments),
+                comment: comments
+                    .get(&(
+                        Some(&materialized_view.schemaname),
+                        &materialized_view.matviewname,
+                        None,
+                    ))
+                    .copied(),
             }
         })
-        .collect()
+        .collect();
+
+    materialized_views.sort_by_key(|m| (&m.schemaname, &m.matviewname));
+    materialized_views
 }
 
 fn get_all_columns<'a>(all: &'a schema::All) -> Vec<Column<'a>> {
@@ -137,6 +1175,21 @@ fn get_all_columns<'a>(all: &'a schema::All) -> Vec<Column<'a>> {
         )
     });
 
+    let attributes_by_column = collect_by_key(all.column_attributes.iter(), |a| {
+        (Some(&a.table_schema), &a.table_name, &a.column_name)
+    });
+    let sequences_by_owner = collect_by_key(all.sequences.iter(), |s| {
+        (
+            s.owned_by_table_schema.as_ref(),
+            s.owned_by_table_name.as_ref(),
+            s.owned_by_column_name.as_ref(),
+        )
+    });
+    let options_by_column = collect_by_key(all.column_options.iter(), |o| {
+        (Some(&o.table_schema), &o.table_name, &o.column_name)
+    });
+    let comments = comments_by_object(all, "column");
+
     let mut columns: Vec<_> = all
         .columns
         .iter()
@@ -149,9 +1202,43 @@ fn get_all_columns<'a>(all: &'a schema::All) -> Vec<Column<'a>> {
                     &column.dtd_identifier,
                 ))
                 .cloned();
+            let attribute = attributes_by_column
+                .get(&(
+                    column.table_schema.as_ref(),
+                    &column.table_name,
+                    &column.column_name,
+                ))
+                .cloned();
+            let owned_sequence = sequences_by_owner
+                .get(&(
+                    column.table_schema.as_ref(),
+                    Some(&column.table_name),
+                    Some(&column.column_name),
+                ))
+                .cloned();
+            let options: Vec<_> = options_by_column
+                .get_vec(&(
+                    column.table_schema.as_ref(),
+                    &column.table_name,
+                    &column.column_name,
+                ))
+                .iter()
+                .flat_map(|v| v.iter())
+                .cloned()
+                .collect();
             Column {
                 column,
                 element_type,
+                attribute,
+                owned_sequence,
+                options: Rc::new(options),
+                comment: comments
+                    .get(&(
+                        column.table_schema.as_ref(),
+                        &column.table_name,
+                        Some(&column.column_name),
+                    ))
+                    .copied(),
             }
         })
         .collect();
@@ -161,10 +1248,57 @@ fn get_all_columns<'a>(all: &'a schema::All) -> Vec<Column<'a>> {
     columns
 }
 
+fn get_all_exclusion_constraints<'a>(
+    all: &'a schema::All,
+    indexes: &[Index<'a>],
+) -> Vec<ExclusionConstraint<'a>> {
+    let indexes_by_constraint = collect_by_key(indexes.iter(), |i| {
+        (
+            &i.index.table_schema,
+            &i.index.table_name,
+            i.index.constraint_name.as_ref(),
+        )
+    });
+    let comments = comments_by_object(all, "constraint");
+
+    all.constraint_details
+        .iter()
+        .filter(|detail| detail.constraint_type == "x")
+        .filter_map(|detail| {
+            let index = indexes_by_constraint
+                .get(&(
+                    &detail.table_schema,
+                    &detail.table_name,
+                    Some(&detail.constraint_name),
+                ))
+                .cloned();
+            if index.is_none() {
+                log::warn!(
+                    "cannot find index of exclusion constraint {}",
+                    detail.constraint_name
+                )
+            }
+
+            Some(ExclusionConstraint {
+                detail,
+                index: index?.clone(),
+                comment: comments
+                    .get(&(
+                        Some(&detail.table_schema),
+                        &detail.table_name,
+                        Some(&detail.constraint_name),
+                    ))
+                    .copied(),
+            })
+        })
+        .collect()
+}
+
 fn get_all_table_constraints<'a>(
     all: &'a schema::All,
     columns: &[Column<'a>],
 ) -> Vec<TableConstraint<'a>> {
+    let comments = comments_by_object(all, "constraint");
     let schema_tables_by_table = collect_by_key(all.tables.iter(), |c| {
         (c.table_schema.as_ref(), &c.table_name)
     });
@@ -176,11 +1310,6 @@ fn get_all_table_constraints<'a>(
         )
     });
 
-    let schema_constraint_column_usage_by_table_constraints =
-        collect_by_key(all.constraint_column_usage.iter(), |c| {
-            (c.constraint_schema.as_ref(), &c.constraint_name)
-        });
-
     let schema_key_column_usage_by_table_keys = collect_by_key(all.key_column_usage.iter(), |c| {
         (c.constraint_schema.as_ref(), &c.constraint_name)
     });
@@ -190,19 +1319,32 @@ fn get_all_table_constraints<'a>(
             (c.constraint_schema.as_ref(), &c.constraint_name)
         });
 
-    let constraint_schema_constraint_name_by_check_constraints =
-        collect_by_key(all.check_constraints.iter(), |c| {
-            (c.constraint_schema.as_ref(), &c.constraint_name)
+    let referential_constraints_by_constraint =
+        collect_by_key(all.referential_constraints.iter(), |r| {
+            (r.constraint_schema.as_ref(), &r.constraint_name)
         });
 
+    let details_by_constraint = collect_by_key(all.constraint_details.iter(), |d| {
+        (Some(&d.table_schema), &d.table_name, &d.constraint_name)
+    });
+
     all.table_constraints
         .iter()
         .map(|table_constraint| -> TableConstraint<'_> {
-            let column_usage: Option<&Vec<_>> = schema_constraint_column_usage_by_table_constraints
-                .get_vec(&(
+            let detail = details_by_constraint
+                .get(&(
+                    table_constraint.table_schema.as_ref(),
+                    &table_constraint.table_name,
+                    &table_constraint.constraint_name,
+                ))
+                .cloned();
+
+            let referential_constraint = referential_constraints_by_constraint
+                .get(&(
                     table_constraint.constraint_schema.as_ref(),
                     &table_constraint.constraint_name,
-                ));
+                ))
+                .cloned();
 
             let key_column_usage: Option<&Vec<_>> =
                 schema_key_column_usage_by_table_keys.get_vec(&(
@@ -210,28 +1352,6 @@ fn get_all_table_constraints<'a>(
                     &table_constraint.constraint_name,
                 ));
 
-            let mut columns: Vec<_> = column_usage
-                .iter()
-                .flat_map(|v| v.iter())
-                .filter_map(|usage| {
-                    let column = column_by_table_column
-                        .get(&(
-                            usage.table_schema.as_ref(),
-                            &usage.table_name,
-                            &usage.column_name,
-                        ))
-                        .cloned();
-                    if column.is_none() {
-                        log::warn!(
-                            "cannot find column {} in table {}",
-                            usage.column_name,
-                            usage.table_name
-                        )
-                    }
-                    column.cloned()
-                })
-                .collect();
-
             let mut key_columns: Vec<_> = key_column_usage
                 .iter()
                 .flat_map(|v| v.iter())
@@ -272,35 +1392,317 @@ fn get_all_table_constraints<'a>(
                 })
                 .collect();
 
-            let mut check_constraints: Vec<_> =
-                constraint_schema_constraint_name_by_check_constraints
-                    .get_vec(&(
-                        table_constraint.constraint_schema.as_ref(),
-                        &table_constraint.constraint_name,
-                    ))
-                    .iter()
-                    .flat_map(|v| v.iter())
-                    .map(|check| CheckConstraint {
-                        check_constraint: check,
-                    })
-                    .collect();
-
-            columns.sort_by_key(|c| (&c.table_schema, &c.table_name, c.ordinal_position));
             key_columns.sort_by_key(|c| (&c.table_schema, &c.table_name, c.ordinal_position));
             tables.sort_by_key(|t| (&t.table_schema, &t.table_name));
-            check_constraints.sort_by_key(|t| (&t.constraint_name));
 
             TableConstraint {
                 table_constraint,
-                columns: Rc::new(columns),
+                detail,
+                referential_constraint,
                 key_columns: Rc::new(key_columns),
                 tables: Rc::new(tables),
-                check_constraints: Rc::new(check_constraints),
+                comment: comments
+                    .get(&(
+                        table_constraint.table_schema.as_ref(),
+                        &table_constraint.table_name,
+                        Some(&table_constraint.constraint_name),
+                    ))
+                    .copied(),
+            }
+        })
+        .collect()
+}
+
+fn get_all_indexes(all: &schema::All) -> Vec<Index<'_>> {
+    let index_columns_by_index = collect_by_key(all.index_columns.iter(), |c| {
+        (&c.index_schema, &c.index_name)
+    });
+
+    all.indexes
+        .iter()
+        .map(|index| {
+            let mut columns: Vec<_> = index_columns_by_index
+                .get_vec(&(&index.index_schema, &index.index_name))
+                .iter()
+                .flat_map(|v| v.iter())
+                .cloned()
+                .collect();
+
+            columns.sort_by_key(|c| c.ordinal_position);
+
+            Index {
+                index,
+                columns: Rc::new(columns),
             }
         })
         .collect()
 }
 
+fn get_all_triggers(all: &schema::All) -> Vec<Trigger<'_>> {
+    all.triggers
+        .iter()
+        .map(|trigger| Trigger { trigger })
+        .collect()
+}
+
+fn get_all_collations(all: &schema::All) -> Vec<Collation<'_>> {
+    let mut collations: Vec<_> = all
+        .collations
+        .iter()
+        .map(|collation| Collation { collation })
+        .collect();
+
+    collations.sort_by_key(|c| (&c.collation_schema, &c.collation_name));
+    collations
+}
+
+fn get_all_schemas(all: &schema::All) -> Vec<Schema<'_>> {
+    let mut schemas: Vec<_> = all.schemas.iter().map(|schema| Schema { schema }).collect();
+
+    schemas.sort_by_key(|s| &s.schema_name);
+    schemas
+}
+
+fn get_all_privileges(all: &schema::All) -> Vec<Privilege<'_>> {
+    let privileges_by_statement = collect_by_key(all.privileges.iter(), |p| {
+        (
+            &p.object_type,
+            &p.object_schema,
+            &p.object_name,
+            &p.column_name,
+            &p.identity_arguments,
+            &p.grantee,
+            p.is_grantable,
+            p.is_grant,
+        )
+    });
+
+    let mut privileges: Vec<_> = privileges_by_statement
+        .iter_all()
+        .map(|(_, rows)| {
+            let mut privilege_types: Vec<_> =
+                rows.iter().map(|p| p.privilege_type.as_str()).collect();
+            privilege_types.sort();
+            Privilege {
+                privilege: rows[0],
+                privilege_types,
+            }
+        })
+        .collect();
+
+    privileges.sort_by_key(|p| {
+        (
+            &p.object_schema,
+            &p.object_name,
+            &p.identity_arguments,
+            &p.column_name,
+            p.is_grant,
+            &p.grantee,
+            p.is_grantable,
+        )
+    });
+    privileges
+}
+
+fn get_all_default_privileges(all: &schema::All) -> Vec<DefaultPrivilege<'_>> {
+    let privileges_by_statement = collect_by_key(all.default_privileges.iter(), |p| {
+        (
+            &p.role_name,
+            &p.schema_name,
+            &p.object_type,
+            &p.grantee,
+            p.is_grantable,
+            p.is_grant,
+        )
+    });
+
+    let mut default_privileges: Vec<_> = privileges_by_statement
+        .iter_all()
+        .map(|(_, rows)| {
+            let mut privilege_types: Vec<_> =
+                rows.iter().map(|p| p.privilege_type.as_str()).collect();
+            privilege_types.sort();
+            DefaultPrivilege {
+                default_privilege: rows[0],
+                privilege_types,
+            }
+        })
+        .collect();
+
+    default_privileges.sort_by_key(|p| {
+        (
+            &p.role_name,
+            &p.schema_name,
+            &p.object_type,
+            p.is_grant,
+            &p.grantee,
+            p.is_grantable,
+        )
+    });
+    default_privileges
+}
+
+fn get_all_extensions(all: &schema::All) -> Vec<Extension<'_>> {
+    let mut extensions: Vec<_> = all
+        .extensions
+        .iter()
+        .map(|extension| Extension { extension })
+        .collect();
+
+    extensions.sort_by_key(|e| &e.extension_name);
+    sort_by_dependencies(
+        extensions,
+        |e| e.extension_name.as_str(),
+        |e| e.requires.iter().map(|r| r.as_str()).collect(),
+    )
+}
+
+fn get_all_routines(all: &schema::All) -> Vec<Routine<'_>> {
+    let type_usage_by_routine = collect_by_key(all.routine_type_usage.iter(), |u| {
+        (&u.routine_schema, &u.routine_name, &u.identity_arguments)
+    });
+    let comments = comments_by_object(all, "function");
+    let mut routines: Vec<_> = all
+        .routines
+        .iter()
+        .map(|routine| Routine {
+            routine,
+            type_usage: Rc::new(
+                type_usage_by_routine
+                    .get_vec(&(
+                        &routine.routine_schema,
+                        &routine.routine_name,
+                        &routine.identity_arguments,
+                    ))
+                    .iter()
+                    .flat_map(|v| v.iter())
+                    .cloned()
+                    .collect(),
+            ),
+            comment: comments
+                .get(&(
+                    Some(&routine.routine_schema),
+                    &routine.routine_name,
+                    Some(&routine.identity_arguments),
+                ))
+                .copied(),
+        })
+        .collect();
+
+    routines.sort_by_key(|r| (&r.routine_schema, &r.routine_name, &r.identity_arguments));
+    routines
+}
+
+fn get_all_aggregates(all: &schema::All) -> Vec<Aggregate<'_>> {
+    let comments = comments_by_object(all, "function");
+    let mut aggregates: Vec<_> = all
+        .aggregates
+        .iter()
+        .map(|aggregate| Aggregate {
+            aggregate,
+            comment: comments
+                .get(&(
+                    Some(&aggregate.aggregate_schema),
+                    &aggregate.aggregate_name,
+                    Some(&aggregate.identity_arguments),
+                ))
+                .copied(),
+        })
+        .collect();
+
+    aggregates.sort_by_key(|a| {
+        (
+            &a.aggregate_schema,
+            &a.aggregate_name,
+            &a.identity_arguments,
+        )
+    });
+    aggregates
+}
+
+fn get_all_foreign_data_wrappers(all: &schema::All) -> Vec<ForeignDataWrapper<'_>> {
+    let options_by_wrapper = collect_by_key(all.foreign_data_wrapper_options.iter(), |o| {
+        &o.foreign_data_wrapper_name
+    });
+
+    let mut wrappers: Vec<_> = all
+        .foreign_data_wrappers
+        .iter()
+        .map(|foreign_data_wrapper| {
+            let options = options_by_wrapper
+                .get_vec(&foreign_data_wrapper.foreign_data_wrapper_name)
+                .iter()
+                .flat_map(|v| v.iter())
+                .cloned()
+                .collect();
+
+            ForeignDataWrapper {
+                foreign_data_wrapper,
+                options: Rc::new(options),
+            }
+        })
+        .collect();
+
+    wrappers.sort_by_key(|w| &w.foreign_data_wrapper_name);
+    wrappers
+}
+
+fn get_all_foreign_servers(all: &schema::All) -> Vec<ForeignServer<'_>> {
+    let options_by_server = collect_by_key(all.foreign_server_options.iter(), |o| {
+        &o.foreign_server_name
+    });
+    let user_mappings_by_server =
+        collect_by_key(all.user_mappings.iter(), |m| &m.foreign_server_name);
+    let options_by_user_mapping = collect_by_key(all.user_mapping_options.iter(), |o| {
+        (&o.foreign_server_name, &o.authorization_identifier)
+    });
+
+    let mut servers: Vec<_> = all
+        .foreign_servers
+        .iter()
+        .map(|foreign_server| {
+            let options = options_by_server
+                .get_vec(&foreign_server.foreign_server_name)
+                .iter()
+                .flat_map(|v| v.iter())
+                .cloned()
+                .collect();
+
+            let mut user_mappings: Vec<_> = user_mappings_by_server
+                .get_vec(&foreign_server.foreign_server_name)
+                .iter()
+                .flat_map(|v| v.iter())
+                .map(|user_mapping| {
+                    let options = options_by_user_mapping
+                        .get_vec(&(
+                            &user_mapping.foreign_server_name,
+                            &user_mapping.authorization_identifier,
+                        ))
+                        .iter()
+                        .flat_map(|v| v.iter())
+                        .cloned()
+                        .collect();
+
+                    UserMapping {
+                        user_mapping,
+                        options: Rc::new(options),
+                    }
+                })
+                .collect();
+            user_mappings.sort_by_key(|m| &m.authorization_identifier);
+
+            ForeignServer {
+                foreign_server,
+                options: Rc::new(options),
+                user_mappings: Rc::new(user_mappings),
+            }
+        })
+        .collect();
+
+    servers.sort_by_key(|s| &s.foreign_server_name);
+    servers
+}
+
 pub fn get_all<'a>(all: &'a schema::All) -> All<'a> {
     let mut ir_start_time = None;
     if log::log_enabled!(log::Level::Info) {
@@ -308,12 +1710,115 @@ pub fn get_all<'a>(all: &'a schema::All) -> All<'a> {
     }
     let columns = get_all_columns(all);
     let table_constraints = get_all_table_constraints(all, &columns);
-    let mut tables = get_all_tables(all, &columns, &table_constraints);
+    let indexes = get_all_indexes(all);
+    let exclusion_constraints = get_all_exclusion_constraints(all, &indexes);
+    let triggers = get_all_triggers(all);
+    let mut tables = get_all_tables(
+        all,
+        &columns,
+        &table_constraints,
+        &indexes,
+        &exclusion_constraints,
+        &triggers,
+    );
+
+    let mut views = sort_by_dependencies(
+        itertools::chain!(
+            get_all_views(all, &columns).into_iter().map(AnyView::View),
+            get_all_materialized_views(all, &indexes)
+                .into_iter()
+                .map(AnyView::MaterializedView),
+        )
+        .collect(),
+        AnyView::key,
+        AnyView::dependencies,
+    );
+    let (routines, early_routines): (Vec<_>, Vec<_>) = get_all_routines(all)
+        .into_iter()
+        .partition(|r| r.uses_relation_row_type());
+    let mut types_and_routines = sort_by_dependencies(
+        itertools::chain!(
+            get_all_domains(all).into_iter().map(TypeOrRoutine::Domain),
+            get_all_composite_types(all)
+                .into_iter()
+                .map(TypeOrRoutine::CompositeType),
+            early_routines.into_iter().map(TypeOrRoutine::Routine),
+        )
+        .collect(),
+        TypeOrRoutine::key,
+        TypeOrRoutine::dependencies,
+    );
+    let mut enums = get_all_enums(all);
+    let mut sequences = get_all_sequences(all, &columns);
+    let collations = get_all_collations(all);
+    let foreign_data_wrappers = get_all_foreign_data_wrappers(all);
+    let foreign_servers = get_all_foreign_servers(all);
+    let schemas = get_all_schemas(all);
+    let privileges = get_all_privileges(all);
+    let default_privileges = get_all_default_privileges(all);
+    let extensions = get_all_extensions(all);
+    let aggregates = get_all_aggregates(all);
+
+    // objects that belong to an extension are created along with it
+    let extension_members: std::collections::HashSet<_> = all
+        .extension_members
+        .iter()
+        .map(|m| {
+            (
+                m.object_type.as_str(),
+                Some(m.object_schema.as_str()),
+                Some(m.object_name.as_str()),
+            )
+        })
+        .collect();
+    let is_relation = |schema: Option<&str>, name: Option<&str>| {
+        extension_members.contains(&("relation", schema, name))
+    };
+    let is_type = |schema: Option<&str>, name: Option<&str>| {
+        extension_members.contains(&("type", schema, name))
+    };
+    tables.retain(|t| !is_relation(t.table_schema.as_deref(), Some(&t.table_name)));
+    views.retain(|v| {
+        let (schema, name) = v.key();
+        !is_relation(schema, name)
+    });
+    sequences.retain(|s| !is_relation(Some(&s.sequence_schema), Some(&s.sequence_name)));
+    enums.retain(|e| !is_type(Some(&e.type_schema), Some(&e.type_name)));
+    types_and_routines.retain(|t| match t {
+        TypeOrRoutine::Routine(_) => true,
+        _ => {
+            let (schema, name, _) = t.key();
+            !is_type(schema, name)
+        }
+    });
 
     tables.sort_by_key(|t| (&t.table_schema, &t.table_name));
+    let tables = sort_by_dependencies(
+        tables,
+        |t| (t.table.table_schema.as_deref(), t.table.table_name.as_str()),
+        |t| {
+            t.parents
+                .iter()
+                .map(|p| (Some(p.parent_schema.as_str()), p.parent_name.as_str()))
+                .collect()
+        },
+    );
 
     let res = All {
+        schemas: Rc::new(schemas),
+        extensions: Rc::new(extensions),
+        collations: Rc::new(collations),
+        foreign_data_wrappers: Rc::new(foreign_data_wrappers),
+        foreign_servers: Rc::new(foreign_servers),
+        enums: Rc::new(enums),
+        types_and_routines: Rc::new(types_and_routines),
+        sequences: Rc::new(sequences),
+        routines: Rc::new(routines),
+        aggregates: Rc::new(aggregates),
         tables: Rc::new(tables),
+        views: Rc::new(views),
+        privileges: Rc::new(privileges),
+        default_privileges: Rc::new(default_privileges),
     };
 
     if let Some(dur) = ir_start_time.and_then(|s| s.elapsed().ok()) {
@@ -325,6 +1830,25 @@ pub fn get_all<'a>(all: &'a schema::All) -> All<'a> {
     res
 }
 
+/// the comments on one kind of object, keyed by the object's schema, its name,
+/// and its column, constraint or function arguments
+fn comments_by_object<'a>(
+    all: &'a schema::All,
+    object_type: &str,
+) -> std::collections::HashMap<(Option<&'a String>, &'a String, Option<&'a String>), &'a str> {
+    all.comments
+        .iter()
+        .filter(|c| c.object_type == object_type)
+        .map(|c| {
+            let member = c.member_name.as_ref().or(c.identity_arguments.as_ref());
+            (
+                (Some(&c.object_schema), &c.object_name, member),
+                c.description.as_str(),
+            )
+        })
+        .collect()
+}
+
 fn collect_by_key<'a, D, K, I, F>(iter: I, func: F) -> multimap::MultiMap<K, &'a D>
 where
     K: std::hash::Hash + std::cmp::Eq,
@@ -333,3 +1857,76 @@ where
 {
     iter.map(|data| (func(data), data)).collect()
 }
+
+/// orders `items` so that each item comes after every item it depends on.
+/// dependencies on keys that are not part of `items` are ignored, and items
+/// that are otherwise unordered keep their relative order.
+fn sort_by_dependencies<T, K, FK, FD>(items: Vec<T>, key: FK, dependencies: FD) -> Vec<T>
+where
+    K: std::hash::Hash + std::cmp::Eq,
+    FK: Fn(&T) -> K,
+    FD: Fn(&T) -> Vec<K>,
+{
+    #[derive(Clone, Copy, PartialEq)]
+    enum Mark {
+        Unvisited,
+        Visiting,
+        Visited,
+    }
+
+    fn visit<T, K, FD>(
+        idx: usize,
+        items: &[T],
+        index_by_key: &std::collections::HashMap<K, usize>,
+        dependencies: &FD,
+        marks: &mut [Mark],
+        order: &mut Vec<usize>,
+    ) where
+        K: std::hash::Hash + std::cmp::Eq,
+        FD: Fn(&T) -> Vec<K>,
+    {
+        match marks[idx] {
+            Mark::Visited => return,
+            Mark::Visiting => {
+                log::warn!("dependency cycle detected, ordering may be incorrect");
+                return;
+            }
+            Mark::Unvisited => {}
+        }
+        marks[idx] = Mark::Visiting;
+        for dependency in dependencies(&items[idx]) {
+            if let Some(&dep_idx) = index_by_key.get(&dependency) {
+                if dep_idx != idx {
+                    visit(dep_idx, items, index_by_key, dependencies, marks, order);
+                }
+            }
+        }
+        marks[idx] = Mark::Visited;
+        order.push(idx);
+    }
+
+    let index_by_key: std::collections::HashMap<K, usize> = items
+        .iter()
+        .enumerate()
+        .map(|(idx, item)| (key(item), idx))
+        .collect();
+
+    let mut marks = vec![Mark::Unvisited; items.len()];
+    let mut order = Vec::with_capacity(items.len());
+    for idx in 0..items.len() {
+        visit(
+            idx,
+            &items,
+            &index_by_key,
+            &dependencies,
+            &mut marks,
+            &mut order,
+        );
+    }
+
+    let mut items: Vec<Option<T>> = items.into_iter().map(Some).collect();
+    order
+        .into_iter()
+        .filter_map(|idx| items[idx].take())
+        .collect()
+}
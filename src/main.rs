@@ -11,7 +11,29 @@ mod ddl;
 mod ir;
 mod schema;
 
+fn cli() -> clap::Command {
+    clap::Command::new("dbstate")
+        .about("prints the schema of the database at DATABASE_URL as sql")
+        .arg(
+            clap::Arg::new("always-name-constraints")
+                .long("always-name-constraints")
+                .action(clap::ArgAction::SetTrue)
+                .help("name every constraint, even the ones postgres would name the same way"),
+        )
+        .arg(
+            clap::Arg::new("no-privileges")
+                .long("no-privileges")
+                .action(clap::ArgAction::SetTrue)
+                .help("leave out grants, revokes and default privileges"),
+        )
+}
+
 async fn run() -> anyhow::Result<()> {
+    let matches = cli().get_matches();
+    let options = ddl::Options {
+        always_name_constraints: matches.get_flag("always-name-constraints"),
+    };
+
     let pool = PgPool::connect(
         &env::var("DATABASE_URL").context("missing DATABASE_URL environment flag")?,
     )
@@ -20,13 +42,466 @@ async fn run() -> anyhow::Result<()> {
     let schema_all = action::get_all(&pool).await?;
     let ir_all = ir::get_all(&schema_all);
 
-    for table in ir_all.tables.iter().filter(|t| !t.table.is_system_schema()) {
-        match ddl::table(table) {
+    // public comes with every database
+    let schemas = ir_all
+        .schemas
+        .iter()
+        .filter(|s| !s.is_system_schema())
+        .filter(|s| s.schema_name != "public");
+    for schema in schemas {
+        match ddl::create_schema(schema) {
+            Ok(schema) => {
+                println!("{}", schema)
+            }
+            Err(e) => {
+                eprintln!("error on schema {}: {}", schema.schema_name, e);
+            }
+        }
+    }
+
+    // everything else may use what the extensions bring along, and plpgsql
+    // comes with every database in pg_catalog
+    for extension in ir_all.extensions.iter().filter(|e| !e.is_system_schema()) {
+        match ddl::extension(extension) {
+            Ok(extension) => {
+                println!("{}", extension)
+            }
+            Err(e) => {
+                eprintln!("error on extension {}: {}", extension.extension_name, e);
+            }
+        }
+    }
+
+    for wrapper in ir_all.foreign_data_wrappers.iter() {
+        match ddl::foreign_data_wrapper(wrapper) {
+            Ok(wrapper) => {
+                println!("{}", wrapper)
+            }
+            Err(e) => {
+                eprintln!(
+                    "error on foreign data wrapper {}: {}",
+                    wrapper.foreign_data_wrapper_name, e
+                );
+            }
+        }
+    }
+
+    for server in ir_all.foreign_servers.iter() {
+        match ddl::foreign_server(server) {
+            Ok(server) => {
+                println!("{}", server)
+            }
+            Err(e) => {
+                eprintln!("error on server {}: {}", server.foreign_server_name, e);
+            }
+        }
+
+        for user_mapping in server.user_mappings.iter() {
+            match ddl::user_mapping(user_mapping) {
+                Ok(user_mapping) => {
+                    println!("{}", user_mapping)
+                }
+                Err(e) => {
+                    eprintln!(
+                        "error on user mapping {}: {}",
+                        user_mapping.authorization_identifier, e
+                    );
+                }
+            }
+        }
+    }
+
+    for collation in ir_all.collations.iter().filter(|c| !c.is_system_schema()) {
+        match ddl::collation(collation) {
+            Ok(collation) => {
+                println!("{}", collation)
+            }
+            Err(e) => {
+                eprintln!("error on collation {}: {}", collation.collation_name, e);
+            }
+        }
+    }
+
+    for enum_type in ir_all.enums.iter().filter(|e| !e.is_system_schema()) {
+        match ddl::enum_type(enum_type) {
+            Ok(enum_type) => {
+                println!("{}", enum_type)
+            }
+            Err(e) => {
+                eprintln!("error on enum {}: {}", enum_type.type_name, e);
+            }
+        }
+    }
+
+    // function bodies are only checked once they run, since they may use
+    // tables that are created further down
+    let routines: Vec<_> = ir_all
+        .routines
+        .iter()
+        .filter(|r| !r.is_system_schema())
+        .collect();
+    let aggregates: Vec<_> = ir_all
+        .aggregates
+        .iter()
+        .filter(|a| !a.is_system_schema())
+        .collect();
+    let has_routines = !routines.is_empty()
+        || !aggregates.is_empty()
+        || ir_all
+            .types_and_routines
+            .iter()
+            .any(|t| matches!(t, ir::TypeOrRoutine::Routine(r) if !r.is_system_schema()));
+    if has_routines {
+        println!("SET check_function_bodies = false;");
+    }
+
+    let types_and_routines = ir_all
+        .types_and_routines
+        .iter()
+        .filter(|t| !t.is_system_schema());
+    for item in types_and_routines {
+        match item {
+            ir::TypeOrRoutine::Domain(domain) => match ddl::domain(domain) {
+                Ok(domain) => {
+                    println!("{}", domain)
+                }
+                Err(e) => {
+                    eprintln!("error on domain {:?}: {}", domain.domain_name, e);
+                }
+            },
+            ir::TypeOrRoutine::CompositeType(composite_type) => {
+                match ddl::composite_type(composite_type) {
+                    Ok(composite_type) => {
+                        println!("{}", composite_type)
+                    }
+                    Err(e) => {
+                        eprintln!("error on type {}: {}", composite_type.type_name, e);
+                    }
+                }
+            }
+            ir::TypeOrRoutine::Routine(routine) => match ddl::routine(routine) {
+                Ok(routine) => {
+                    println!("{}", routine)
+                }
+                Err(e) => {
+                    eprintln!("error on routine {}: {}", routine.routine_name, e);
+                }
+            },
+        }
+    }
+
+    let sequences: Vec<_> = ir_all
+        .sequences
+        .iter()
+        .filter(|s| !s.is_system_schema())
+        .filter(|s| !ddl::is_implicit_sequence(s))
+        .collect();
+    for sequence in sequences.iter() {
+        match ddl::sequence(sequence) {
+            Ok(sequence) => {
+                println!("{}", sequence)
+            }
+            Err(e) => {
+                eprintln!("error on sequence {}: {}", sequence.sequence_name, e);
+            }
+        }
+    }
+
+    let tables = ir_all
+        .tables
+        .iter()
+        .filter(|t| !t.table.is_system_schema())
+        .filter(|t| match t.table.table_type.as_deref() {
+            // views are printed once all the tables exist
+            Some("VIEW") => false,
+            // temporary tables only live as long as the session that made them
+            Some("LOCAL TEMPORARY") => false,
+            _ => true,
+        });
+    for table in tables.clone() {
+        match ddl::table(table, &options) {
             Ok(table) => {
                 println!("{}", table)
             }
             Err(e) => {
                 eprintln!("error on table {}: {}", table.table.table_name, e);
+                continue;
+            }
+        }
+
+        match ddl::inherited_column_options(table) {
+            Ok(options) => {
+                for option in options {
+                    println!("{}", option)
+                }
+            }
+            Err(e) => {
+                eprintln!("error on table {}: {}", table.table.table_name, e);
+            }
+        }
+
+        // partitions of indexes are created along with the index on the parent
+        let indexes = table
+            .indexes
+            .iter()
+            .filter(|i| !i.is_constraint_index() && !i.is_partition);
+        for index in indexes {
+            match ddl::index(index) {
+                Ok(index) => {
+                    println!("{}", index)
+                }
+                Err(e) => {
+                    eprintln!("error on index {}: {}", index.index_name, e);
+                }
+            }
+        }
+    }
+
+    // foreign keys and constraints that were never validated are added once
+    // all the tables exist
+    for table in tables.clone() {
+        match ddl::added_constraints(table, &options) {
+            Ok(constraints) => {
+                for constraint in constraints {
+                    println!("{}", constraint)
+                }
+            }
+            Err(e) => {
+                eprintln!("error on table {}: {}", table.table.table_name, e);
+            }
+        }
+    }
+
+    for sequence in sequences.iter() {
+        match ddl::sequence_owner(sequence) {
+            Ok(Some(owner)) => {
+                println!("{}", owner)
+            }
+            Ok(None) => {}
+            Err(e) => {
+                eprintln!("error on sequence {}: {}", sequence.sequence_name, e);
+            }
+        }
+    }
+
+    // routines that take or return the rows of a table need the table first
+    for routine in routines {
+        match ddl::routine(routine) {
+            Ok(routine) => {
+                println!("{}", routine)
+            }
+            Err(e) => {
+                eprintln!("error on routine {}: {}", routine.routine_name, e);
+            }
+        }
+    }
+
+    // aggregates come after the functions they are built from
+    for aggregate in aggregates {
+        match ddl::aggregate(aggregate) {
+            Ok(aggregate) => {
+                println!("{}", aggregate)
+            }
+            Err(e) => {
+                eprintln!("error on aggregate {}: {}", aggregate.aggregate_name, e);
+            }
+        }
+    }
+    if has_routines {
+        println!("RESET check_function_bodies;");
+    }
+
+    for view in ir_all.views.iter().filter(|v| !v.is_system_schema()) {
+        match view {
+            ir::AnyView::View(view) => match ddl::view(view) {
+                Ok(view) => {
+                    println!("{}", view)
+                }
+                Err(e) => {
+                    eprintln!("error on view {:?}: {}", view.table_name, e);
+                }
+            },
+            ir::AnyView::MaterializedView(view) => {
+                match ddl::materialized_view(view) {
+                    Ok(view) => {
+                        println!("{}", view)
+                    }
+                    Err(e) => {
+                        eprintln!("error on materialized view {}: {}", view.matviewname, e);
+                        continue;
+                    }
+                }
+
+                for index in view.indexes.iter() {
+                    match ddl::index(index) {
+                        Ok(index) => {
+                            println!("{}", index)
+                        }
+                        Err(e) => {
+                            eprintln!("error on index {}: {}", index.index_name, e);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // triggers can be defined on views as well, so they come last
+    for table in ir_all.tables.iter().filter(|t| !t.table.is_system_schema()) {
+        for trigger in table.triggers.iter().filter(|t| !t.is_partition_clone) {
+            match ddl::trigger(trigger) {
+                Ok(trigger) => {
+                    println!("{}", trigger)
+                }
+                Err(e) => {
+                    eprintln!("error on trigger {}: {}", trigger.trigger_name, e);
+                }
+            }
+        }
+    }
+
+    // policies may use any table or view in their expressions
+    for table in ir_all.tables.iter().filter(|t| !t.table.is_system_schema()) {
+        match ddl::row_security(table) {
+            Ok(statements) => {
+                for statement in statements {
+                    println!("{}", statement)
+                }
+            }
+            Err(e) => {
+                eprintln!("error on table {}: {}", table.table.table_name, e);
+            }
+        }
+    }
+
+    // comments come once every object exists, constraints added later included
+    for enum_type in ir_all.enums.iter().filter(|e| !e.is_system_schema()) {
+        match ddl::enum_comment(enum_type) {
+            Ok(Some(comment)) => {
+                println!("{}", comment)
+            }
+            Ok(None) => {}
+            Err(e) => {
+                eprintln!("error on enum {}: {}", enum_type.type_name, e);
+            }
+        }
+    }
+
+    let types_and_routines = ir_all
+        .types_and_routines
+        .iter()
+        .filter(|t| !t.is_system_schema());
+    for item in types_and_routines {
+        match item {
+            ir::TypeOrRoutine::Domain(domain) => match ddl::domain_comments(domain) {
+                Ok(comments) => {
+                    for comment in comments {
+                        println!("{}", comment)
+                    }
+                }
+                Err(e) => {
+                    eprintln!("error on domain {:?}: {}", domain.domain_name, e);
+                }
+            },
+            ir::TypeOrRoutine::CompositeType(composite_type) => {
+                match ddl::composite_type_comment(composite_type) {
+                    Ok(Some(comment)) => {
+                        println!("{}", comment)
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        eprintln!("error on type {}: {}", composite_type.type_name, e);
+                    }
+                }
+            }
+            ir::TypeOrRoutine::Routine(routine) => match ddl::routine_comment(routine) {
+                Ok(Some(comment)) => {
+                    println!("{}", comment)
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    eprintln!("error on routine {}: {}", routine.routine_name, e);
+                }
+            },
+        }
+    }
+
+    for routine in ir_all.routines.iter().filter(|r| !r.is_system_schema()) {
+        match ddl::routine_comment(routine) {
+            Ok(Some(comment)) => {
+                println!("{}", comment)
+            }
+            Ok(None) => {}
+            Err(e) => {
+                eprintln!("error on routine {}: {}", routine.routine_name, e);
+            }
+        }
+    }
+
+    for aggregate in ir_all.aggregates.iter().filter(|a| !a.is_system_schema()) {
+        match ddl::aggregate_comment(aggregate) {
+            Ok(Some(comment)) => {
+                println!("{}", comment)
+            }
+            Ok(None) => {}
+            Err(e) => {
+                eprintln!("error on aggregate {}: {}", aggregate.aggregate_name, e);
+            }
+        }
+    }
+
+    for table in tables {
+        match ddl::table_comments(table) {
+            Ok(comments) => {
+                for comment in comments {
+                    println!("{}", comment)
+                }
+            }
+            Err(e) => {
+                eprintln!("error on table {}: {}", table.table.table_name, e);
+            }
+        }
+    }
+
+    for view in ir_all.views.iter().filter(|v| !v.is_system_schema()) {
+        match ddl::view_comments(view) {
+            Ok(comments) => {
+                for comment in comments {
+                    println!("{}", comment)
+                }
+            }
+            Err(e) => {
+                eprintln!("error on view: {}", e);
+            }
+        }
+    }
+
+    if matches.get_flag("no-privileges") {
+        return Ok(());
+    }
+
+    for privilege in ir_all.privileges.iter().filter(|p| !p.is_system_schema()) {
+        match ddl::privilege(privilege) {
+            Ok(privilege) => {
+                println!("{}", privilege)
+            }
+            Err(e) => {
+                eprintln!("error on privileges of {}: {}", privilege.object_name, e);
+            }
+        }
+    }
+
+    // default privileges come last so they leave the objects above alone
+    for default_privilege in ir_all.default_privileges.iter() {
+        match ddl::default_privilege(default_privilege) {
+            Ok(default_privilege) => {
+                println!("{}", default_privilege)
+            }
+            Err(e) => {
+                eprintln!(
+                    "error on default privileges of {}: {}",
+                    default_privilege.role_name, e
+                );
             }
         }
     }
@@ -0,0 +1,125 @@
+use super::{Column, Domain, ElementType};
+
+/// information_schema describes a data type with the same set of columns
+/// wherever one appears, this gives a common view over them.
+pub trait DataType {
+    /// the built-in type name, ARRAY, or USER-DEFINED
+    fn data_type(&self) -> Option<&str>;
+
+    fn character_maximum_length(&self) -> Option<i32>;
+
+    fn numeric_precision(&self) -> Option<i32>;
+
+    fn numeric_scale(&self) -> Option<i32>;
+
+    fn datetime_precision(&self) -> Option<i32>;
+
+    /// the interval fields, including the seconds precision when one was declared
+    fn interval_type(&self) -> Option<&str>;
+
+    fn udt_schema(&self) -> Option<&str>;
+
+    fn udt_name(&self) -> Option<&str>;
+}
+
+impl DataType for Column {
+    fn data_type(&self) -> Option<&str> {
+        Some(&self.data_type)
+    }
+
+    fn character_maximum_length(&self) -> Option<i32> {
+        self.character_maximum_length
+    }
+
+    fn numeric_precision(&self) -> Option<i32> {
+        self.numeric_precision
+    }
+
+    fn numeric_scale(&self) -> Option<i32> {
+        self.numeric_scale
+    }
+
+    fn datetime_precision(&self) -> Option<i32> {
+        self.datetime_precision
+    }
+
+    fn interval_type(&self) -> Option<&str> {
+        self.interval_type.as_deref()
+    }
+
+    fn udt_schema(&self) -> Option<&str> {
+        self.udt_schema.as_deref()
+    }
+
+    fn udt_name(&self) -> Option<&str> {
+        self.udt_name.as_deref()
+    }
+}
+
+impl DataType for Domain {
+    fn data_type(&self) -> Option<&str> {
+        self.data_type.as_deref()
+    }
+
+    fn character_maximum_length(&self) -> Option<i32> {
+        self.character_maximum_length
+    }
+
+    fn numeric_precision(&self) -> Option<i32> {
+        self.numeric_precision
+    }
+
+    fn numeric_scale(&self) -> Option<i32> {
+        self.numeric_scale
+    }
+
+    fn datetime_precision(&self) -> Option<i32> {
+        self.datetime_precision
+    }
+
+    fn interval_type(&self) -> Option<&str> {
+        self.interval_type.as_deref()
+    }
+
+    fn udt_schema(&self) -> Option<&str> {
+        self.udt_schema.as_deref()
+    }
+
+    fn udt_name(&self) -> Option<&str> {
+        self.udt_name.as_deref()
+    }
+}
+
+impl DataType for ElementType {
+    fn data_type(&self) -> Option<&str> {
+        self.data_type.as_deref()
+    }
+
+    fn character_maximum_length(&self) -> Option<i32> {
+        self.character_maximum_length
+    }
+
+    fn numeric_precision(&self) -> Option<i32> {
+        self.numeric_precision
+    }
+
+    fn numeric_scale(&self) -> Option<i32> {
+        self.numeric_scale
+    }
+
+    fn datetime_precision(&self) -> Option<i32> {
+        self.datetime_precision
+    }
+
+    fn interval_type(&self) -> Option<&str> {
+        self.interval_type.as_deref()
+    }
+
+    fn udt_schema(&self) -> Option<&str> {
+        self.udt_schema.as_deref()
+    }
+
+    fn udt_name(&self) -> Option<&str> {
+        self.udt_name.as_deref()
+    }
+}
@@ -1,5 +1,11 @@
+// the structs in this module mirror their source views column for column,
+// so not every field is read.
+#![allow(dead_code)]
+
+pub use data_type::DataType;
 pub use yes_no::YesNo;
 
+mod data_type;
 mod yes_no;
 
 #[derive(Debug, Clone)]
@@ -7,12 +13,52 @@ pub struct All {
     pub tables: Vec<Table>,
     pub columns: Vec<Column>,
     pub views: Vec<View>,
+    pub view_table_usage: Vec<ViewTableUsage>,
+    pub triggers: Vec<Trigger>,
     pub table_constraints: Vec<TableConstraint>,
     pub constraint_column_usage: Vec<ConstraintColumnUsage>,
     pub key_column_usage: Vec<KeyColumnUsage>,
     pub constraint_table_usage: Vec<ConstraintTableUsage>,
+    pub referential_constraints: Vec<ReferentialConstraint>,
     pub element_types: Vec<ElementType>,
     pub check_constraints: Vec<CheckConstraint>,
+    pub enums: Vec<Enum>,
+    pub enum_labels: Vec<EnumLabel>,
+    pub composite_types: Vec<CompositeType>,
+    pub domains: Vec<Domain>,
+    pub domain_constraints: Vec<DomainConstraint>,
+    pub composite_type_attributes: Vec<CompositeTypeAttribute>,
+    pub column_attributes: Vec<ColumnAttribute>,
+    pub sequences: Vec<Sequence>,
+    pub constraint_details: Vec<ConstraintDetail>,
+    pub partitioned_tables: Vec<PartitionedTable>,
+    pub table_inheritance: Vec<TableInheritance>,
+    pub foreign_data_wrappers: Vec<ForeignDataWrapper>,
+    pub foreign_data_wrapper_options: Vec<ForeignDataWrapperOption>,
+    pub foreign_servers: Vec<ForeignServer>,
+    pub foreign_server_options: Vec<ForeignServerOption>,
+    pub foreign_tables: Vec<ForeignTable>,
+    pub foreign_table_options: Vec<ForeignTableOption>,
+    pub column_options: Vec<ColumnOption>,
+    pub user_mappings: Vec<UserMapping>,
+    pub user_mapping_options: Vec<UserMappingOption>,
+    pub collations: Vec<Collation>,
+    pub materialized_views: Vec<MaterializedView>,
+    pub materialized_view_usage: Vec<MaterializedViewUsage>,
+    pub routines: Vec<Routine>,
+    pub routine_type_usage: Vec<RoutineTypeUsage>,
+    pub domain_routine_usage: Vec<DomainRoutineUsage>,
+    pub aggregates: Vec<Aggregate>,
+    pub extensions: Vec<Extension>,
+    pub extension_members: Vec<ExtensionMember>,
+    pub schemas: Vec<Schema>,
+    pub privileges: Vec<Privilege>,
+    pub default_privileges: Vec<DefaultPrivilege>,
+    pub row_security: Vec<RowSecurity>,
+    pub policies: Vec<Policy>,
+    pub comments: Vec<Comment>,
+    pub indexes: Vec<Index>,
+    pub index_columns: Vec<IndexColumn>,
 }
 
 #[derive(Debug, Clone, sqlx::FromRow)]
@@ -54,14 +100,67 @@ pub struct Table {
     pub commit_action: Option<String>,
 }
 
-impl Table {
+/// Returns `true` if the schema belongs to postgres itself rather than to the user.
+/// That is the catalogs, the toast tables, and the schemas sessions keep their
+/// temporary objects in.
+pub fn is_system_schema(schema: Option<&str>) -> bool {
+    match schema {
+        Some("pg_catalog" | "information_schema" | "pg_toast") => true,
+        Some(schema) => schema.starts_with("pg_temp_") || schema.starts_with("pg_toast_temp_"),
+        None => false,
+    }
+}
+
+/// Schemas read from pg_namespace. One row per schema; schemas that belong to an extension are left out, they come with the extension.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Schema {
+    /// Name of the schema
+    pub schema_name: String,
+
+    /// Name of the owner of the schema
+    pub schema_owner: String,
+}
+
+impl Schema {
+    pub fn is_system_schema(&self) -> bool {
+        is_system_schema(Some(&self.schema_name))
+    }
+}
+
+/// Collations read from pg_collation. One row per collation; collations that belong to an extension are left out, they come with the extension.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Collation {
+    /// Name of the schema that contains the collation
+    pub collation_schema: String,
+
+    /// Name of the collation
+    pub collation_name: String,
+
+    /// Provider of the collation: icu, libc or default
+    pub provider: String,
+
+    /// LC_COLLATE of a libc collation, null for other providers
+    pub lc_collate: Option<String>,
+
+    /// LC_CTYPE of a libc collation, null for other providers
+    pub lc_ctype: Option<String>,
+
+    /// Locale of an icu collation, null for other providers
+    pub icu_locale: Option<String>,
+
+    /// If false, strings that are not byte-wise equal can compare as equal
+    pub is_deterministic: bool,
+}
+
+impl Collation {
     pub fn is_system_schema(&self) -> bool {
-        let system_schemas = [Some("pg_catalog"), Some("information_schema")];
-        let found = system_schemas
-            .iter()
-            .find(|s| self.table_schema.as_ref().map(String::as_str) == **s);
+        is_system_schema(Some(&self.collation_schema))
+    }
+}
 
-        found.is_some()
+impl Table {
+    pub fn is_system_schema(&self) -> bool {
+        is_system_schema(self.table_schema.as_deref())
     }
 }
 
@@ -98,58 +197,54 @@ pub struct View {
     pub is_trigger_insertable_into: Option<YesNo>,
 }
 
-#[derive(Debug, Clone, sqlx::FromRow)]
-pub struct Trigger {
-    /// Name of the database that contains the trigger (always the current database)
-    pub trigger_catalog: Option<String>,
-
-    /// Name of the schema that contains the trigger
-    pub trigger_schema: Option<String>,
-
-    /// Name of the trigger
-    pub trigger_name: Option<String>,
-
-    /// Event that fires the trigger (INSERT, UPDATE, or DELETE)
-    pub event_manipulation: Option<String>,
-
-    /// Name of the database that contains the table that the trigger is defined on (always the current database)
-    pub event_object_catalog: Option<String>,
+impl View {
+    pub fn is_system_schema(&self) -> bool {
+        is_system_schema(self.table_schema.as_deref())
+    }
+}
 
-    /// Name of the schema that contains the table that the trigger is defined on
-    pub event_object_schema: Option<String>,
+/// The view view_table_usage identifies all tables that are used in the query expression of a view (the SELECT statement that defines the view). A table is only included if that table is owned by a currently enabled role.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ViewTableUsage {
+    /// Name of the database that contains the view (always the current database)
+    pub view_catalog: Option<String>,
 
-    /// Name of the table that the trigger is defined on
-    pub event_object_table: Option<String>,
+    /// Name of the schema that contains the view
+    pub view_schema: Option<String>,
 
-    /// Firing order among triggers on the same table having the same event_manipulation, action_timing, and action_orientation. In PostgreSQL, triggers are fired in name order, so this column reflects that.
-    pub action_order: Option<i32>,
+    /// Name of the view
+    pub view_name: String,
 
-    /// WHEN condition of the trigger, null if none (also null if the table is not owned by a currently enabled role)
-    pub action_condition: Option<String>,
+    /// Name of the database that contains the table that is used by the view (always the current database)
+    pub table_catalog: Option<String>,
 
-    /// Statement that is executed by the trigger (currently always EXECUTE FUNCTION function(...))
-    pub action_statement: Option<String>,
+    /// Name of the schema that contains the table that is used by the view
+    pub table_schema: Option<String>,
 
-    /// Identifies whether the trigger fires once for each processed row or once for each statement (ROW or STATEMENT)
-    pub action_orientation: Option<String>,
+    /// Name of the table that is used by the view
+    pub table_name: String,
+}
 
-    /// Time at which the trigger fires (BEFORE, AFTER, or INSTEAD OF)
-    pub action_timing: Option<String>,
+/// Triggers read from pg_trigger, leaving out the ones postgres creates internally (for foreign keys, say). information_schema.triggers cannot be used, as it has no TRUNCATE triggers and does not tell constraint triggers apart. One row per trigger.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Trigger {
+    /// Name of the schema that contains the trigger (the schema of its table)
+    pub trigger_schema: String,
 
-    /// Name of the “old” transition table, or null if none
-    pub action_reference_old_table: Option<String>,
+    /// Name of the trigger
+    pub trigger_name: String,
 
-    /// Name of the “new” transition table, or null if none
-    pub action_reference_new_table: Option<String>,
+    /// Name of the schema that contains the table that the trigger is defined on
+    pub event_object_schema: String,
 
-    /// Applies to a feature not available in PostgreSQL
-    pub action_reference_old_row: Option<String>,
+    /// Name of the table that the trigger is defined on
+    pub event_object_table: String,
 
-    /// Applies to a feature not available in PostgreSQL
-    pub action_reference_new_row: Option<String>,
+    /// The CREATE TRIGGER or CREATE CONSTRAINT TRIGGER statement, from pg_get_triggerdef
+    pub definition: String,
 
-    /// Applies to a feature not available in PostgreSQL
-    pub created: chrono::DateTime<chrono::Utc>,
+    /// If true, the trigger is a clone of a trigger on the partitioned parent table and is created along with it
+    pub is_partition_clone: bool,
 }
 
 #[derive(Debug, Clone, sqlx::FromRow)]
@@ -183,7 +278,10 @@ pub struct TableConstraint {
 
     /// Applies to a feature not available in PostgreSQL (currently always YES)
     pub enforced: Option<YesNo>,
-    // NOTE there is an additional "nulls_distinct" that's only available in postgres v15 and up
+
+    /// If the constraint is a unique constraint, then YES if the constraint treats nulls as distinct or NO if it treats nulls as not distinct, otherwise null for other types of constraints. Only available in postgres v15 and up
+    #[sqlx(default)]
+    pub nulls_distinct: Option<YesNo>,
 }
 
 #[derive(Debug, Clone, sqlx::FromRow)]
@@ -399,6 +497,37 @@ pub struct ConstraintTableUsage {
     pub constraint_name: String,
 }
 
+/// The view referential_constraints contains all referential (foreign key) constraints in the current database. Only those constraints are shown for which the current user has write access to the referencing table (by way of being the owner or having some privilege other than SELECT).
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ReferentialConstraint {
+    /// Name of the database containing the constraint (always the current database)
+    pub constraint_catalog: Option<String>,
+
+    /// Name of the schema containing the constraint
+    pub constraint_schema: Option<String>,
+
+    /// Name of the constraint
+    pub constraint_name: String,
+
+    /// Name of the database that contains the unique or primary key constraint that the foreign key constraint references (always the current database)
+    pub unique_constraint_catalog: Option<String>,
+
+    /// Name of the schema that contains the unique or primary key constraint that the foreign key constraint references
+    pub unique_constraint_schema: Option<String>,
+
+    /// Name of the unique or primary key constraint that the foreign key constraint references
+    pub unique_constraint_name: Option<String>,
+
+    /// Match option of the foreign key constraint: FULL, PARTIAL, or NONE.
+    pub match_option: String,
+
+    /// Update rule of the foreign key constraint: CASCADE, SET NULL, SET DEFAULT, RESTRICT, or NO ACTION.
+    pub update_rule: String,
+
+    /// Delete rule of the foreign key constraint: CASCADE, SET NULL, SET DEFAULT, RESTRICT, or NO ACTION.
+    pub delete_rule: String,
+}
+
 /// The view element_types contains the data type descriptors of the elements of arrays. When a table column, composite-type attribute, domain, function parameter, or function return value is defined to be of an array type, the respective information schema view only contains ARRAY in the column data_type. To obtain information on the element type of the array, you can join the respective view with this view. For example, to show the columns of a table with data types and array element types, if applicable, you could do:
 #[derive(Debug, Clone, sqlx::FromRow)]
 pub struct ElementType {
@@ -490,6 +619,131 @@ pub struct ElementType {
     pub dtd_identifier: Option<String>,
 }
 
+/// The view domains contains all domains defined in the current database. Only those domains are shown that the current user has access to (by way of being the owner or having some privilege).
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Domain {
+    /// Name of the database that contains the domain (always the current database)
+    pub domain_catalog: Option<String>,
+
+    /// Name of the schema that contains the domain
+    pub domain_schema: Option<String>,
+
+    /// Name of the domain
+    pub domain_name: Option<String>,
+
+    /// Data type of the domain, if it is a built-in type, or ARRAY if it is some array (in that case, see the view element_types), else USER-DEFINED (in that case, the type is identified in udt_name and associated columns).
+    pub data_type: Option<String>,
+
+    /// If the domain has a character or bit string type, the declared maximum length; null for all other data types or if no maximum length was declared.
+    pub character_maximum_length: Option<i32>,
+
+    /// If the domain has a character type, the maximum possible length in octets (bytes) of a datum; null for all other data types. The maximum octet length depends on the declared character maximum length (see above) and the server encoding.
+    pub character_octet_length: Option<i32>,
+
+    /// Applies to a feature not available in PostgreSQL
+    pub character_set_catalog: Option<String>,
+
+    /// Applies to a feature not available in PostgreSQL
+    pub character_set_schema: Option<String>,
+
+    /// Applies to a feature not available in PostgreSQL
+    pub character_set_name: Option<String>,
+
+    /// Name of the database containing the collation of the domain (always the current database), null if default or the data type of the domain is not collatable
+    pub collation_catalog: Option<String>,
+
+    /// Name of the schema containing the collation of the domain, null if default or the data type of the domain is not collatable
+    pub collation_schema: Option<String>,
+
+    /// Name of the collation of the domain, null if default or the data type of the domain is not collatable
+    pub collation_name: Option<String>,
+
+    /// If the domain has a numeric type, this column contains the (declared or implicit) precision of the type for this domain. The precision indicates the number of significant digits. It can be expressed in decimal (base 10) or binary (base 2) terms, as specified in the column numeric_precision_radix. For all other data types, this column is null.
+    pub numeric_precision: Option<i32>,
+
+    /// If the domain has a numeric type, this column indicates in which base the values in the columns numeric_precision and numeric_scale are expressed. The value is either 2 or 10. For all other data types, this column is null.
+    pub numeric_precision_radix: Option<i32>,
+
+    /// If the domain has an exact numeric type, this column contains the (declared or implicit) scale of the type for this domain. The scale indicates the number of significant digits to the right of the decimal point. It can be expressed in decimal (base 10) or binary (base 2) terms, as specified in the column numeric_precision_radix. For all other data types, this column is null.
+    pub numeric_scale: Option<i32>,
+
+    /// If data_type identifies a date, time, timestamp, or interval type, the fractional seconds precision of the type for this domain; null for all other data types.
+    pub datetime_precision: Option<i32>,
+
+    /// If data_type identifies an interval type, this column contains the specification which fields the intervals include for this domain, e.g., YEAR TO MONTH, DAY TO SECOND, etc. If no field restrictions were specified (that is, the interval accepts all fields), and for all other data types, this field is null.
+    pub interval_type: Option<String>,
+
+    /// Applies to a feature not available in PostgreSQL (see datetime_precision for the fractional seconds precision of interval type domains)
+    pub interval_precision: Option<i32>,
+
+    /// Default expression of the domain
+    pub domain_default: Option<String>,
+
+    /// Name of the database that the domain data type is defined in (always the current database)
+    pub udt_catalog: Option<String>,
+
+    /// Name of the schema that the domain data type is defined in
+    pub udt_schema: Option<String>,
+
+    /// Name of the domain data type
+    pub udt_name: Option<String>,
+
+    /// Applies to a feature not available in PostgreSQL
+    pub scope_catalog: Option<String>,
+
+    /// Applies to a feature not available in PostgreSQL
+    pub scope_schema: Option<String>,
+
+    /// Applies to a feature not available in PostgreSQL
+    pub scope_name: Option<String>,
+
+    /// Always null, because arrays always have unlimited maximum cardinality in PostgreSQL
+    pub maximum_cardinality: Option<i32>,
+
+    /// An identifier of the data type descriptor of the domain, unique among the data type descriptors pertaining to the domain (which is trivial, because a domain only contains one data type descriptor). This is mainly useful for joining with other instances of such identifiers. (The specific format of the identifier is not defined and not guaranteed to remain the same in future versions.)
+    pub dtd_identifier: Option<String>,
+
+    /// Not part of information_schema.domains, read from pg_type.typnotnull since the view does not report NOT NULL domains
+    pub is_not_null: bool,
+
+    /// Not part of information_schema.domains. If the domain is over an array, its base type as format_type prints it with the domain's type modifier, since data_type drops the modifier of the elements (varchar(5)[] reads as character varying[]); null otherwise.
+    pub array_data_type: Option<String>,
+}
+
+impl Domain {
+    pub fn is_system_schema(&self) -> bool {
+        is_system_schema(self.domain_schema.as_deref())
+    }
+}
+
+/// The view domain_constraints contains all constraints belonging to domains defined in the current database. Only those domains are shown that the current user has access to (by way of being the owner or having some privilege).
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct DomainConstraint {
+    /// Name of the database that contains the constraint (always the current database)
+    pub constraint_catalog: Option<String>,
+
+    /// Name of the schema that contains the constraint
+    pub constraint_schema: Option<String>,
+
+    /// Name of the constraint
+    pub constraint_name: String,
+
+    /// Name of the database that contains the domain (always the current database)
+    pub domain_catalog: Option<String>,
+
+    /// Name of the schema that contains the domain
+    pub domain_schema: Option<String>,
+
+    /// Name of the domain
+    pub domain_name: String,
+
+    /// YES if the constraint is deferrable, NO if not
+    pub is_deferrable: Option<YesNo>,
+
+    /// YES if the constraint is deferrable and initially deferred, NO if not
+    pub initially_deferred: Option<YesNo>,
+}
+
 /// The view check_constraints contains all check constraints, either defined on a table or on a domain, that are owned by a currently enabled role. (The owner of the table or domain is the owner of the constraint.)
 #[derive(Debug, Clone, sqlx::FromRow)]
 pub struct CheckConstraint {
@@ -505,3 +759,874 @@ pub struct CheckConstraint {
     /// The check expression of the check constraint
     pub check_clause: String,
 }
+
+/// Indexes are not part of the SQL standard, so they are read from pg_index instead of information_schema. One row per index.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Index {
+    /// Name of the schema that contains the index
+    pub index_schema: String,
+
+    /// Name of the index
+    pub index_name: String,
+
+    /// Name of the schema that contains the indexed table
+    pub table_schema: String,
+
+    /// Name of the indexed table
+    pub table_name: String,
+
+    /// Name of the index access method (btree, hash, gist, gin, ...)
+    pub index_method: String,
+
+    /// If true, this is a unique index
+    pub is_unique: bool,
+
+    /// If true, this index represents the primary key of the table
+    pub is_primary: bool,
+
+    /// Predicate of a partial index, null if the index is not partial
+    pub predicate: Option<String>,
+
+    /// Name of the PRIMARY KEY, UNIQUE or EXCLUDE constraint that owns this index, null if the index was created on its own
+    pub constraint_name: Option<String>,
+
+    /// If true, the index is a partition of an index on the partitioned parent table and is created along with it
+    pub is_partition: bool,
+}
+
+impl Index {
+    /// Returns `true` if the index is created implicitly by a table constraint.
+    pub fn is_constraint_index(&self) -> bool {
+        self.constraint_name.is_some()
+    }
+}
+
+/// One row per column (or expression) of an index, read from pg_index. Key columns come first, followed by the INCLUDE columns.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct IndexColumn {
+    /// Name of the schema that contains the index
+    pub index_schema: String,
+
+    /// Name of the index
+    pub index_name: String,
+
+    /// Ordinal position of the column within the index (count starts at 1)
+    pub ordinal_position: i32,
+
+    /// If true, this is a non-key column added with INCLUDE
+    pub is_included: bool,
+
+    /// Name of the indexed table column, null if this is an expression
+    pub column_name: Option<String>,
+
+    /// Column name or expression as reported by pg_get_indexdef
+    pub definition: String,
+
+    /// Name of the schema that contains the operator class, null if the default operator class for the type is used
+    pub opclass_schema: Option<String>,
+
+    /// Name of the operator class, null if the default operator class for the type is used
+    pub opclass_name: Option<String>,
+
+    /// Name of the schema that contains the collation, null if the collation is the default for the column
+    pub collation_schema: Option<String>,
+
+    /// Name of the collation, null if the collation is the default for the column
+    pub collation_name: Option<String>,
+
+    /// If true, the column is sorted in descending order
+    pub is_descending: bool,
+
+    /// If true, nulls sort before non-null values
+    pub is_nulls_first: bool,
+}
+
+/// Enum types are a PostgreSQL extension, so they are read from pg_type instead of information_schema. One row per enum type.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Enum {
+    /// Name of the schema that contains the enum type
+    pub type_schema: String,
+
+    /// Name of the enum type
+    pub type_name: String,
+}
+
+impl Enum {
+    pub fn is_system_schema(&self) -> bool {
+        is_system_schema(Some(&self.type_schema))
+    }
+}
+
+/// One row per label of an enum type, read from pg_enum.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct EnumLabel {
+    /// Name of the schema that contains the enum type
+    pub type_schema: String,
+
+    /// Name of the enum type
+    pub type_name: String,
+
+    /// The textual label for this enum value
+    pub label: String,
+
+    /// The sort position of this enum value within its enum type. Labels added with ALTER TYPE ... ADD VALUE BEFORE/AFTER get fractional positions.
+    pub sort_order: f32,
+}
+
+/// Standalone composite types (CREATE TYPE ... AS (...)), read from pg_type. The row types that back every table are not included. One row per type.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct CompositeType {
+    /// Name of the schema that contains the composite type
+    pub type_schema: String,
+
+    /// Name of the composite type
+    pub type_name: String,
+}
+
+impl CompositeType {
+    pub fn is_system_schema(&self) -> bool {
+        is_system_schema(Some(&self.type_schema))
+    }
+}
+
+/// One row per attribute of a standalone composite type, read from pg_attribute.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct CompositeTypeAttribute {
+    /// Name of the schema that contains the composite type
+    pub type_schema: String,
+
+    /// Name of the composite type
+    pub type_name: String,
+
+    /// Name of the attribute
+    pub attribute_name: String,
+
+    /// Ordinal position of the attribute within the composite type (count starts at 1)
+    pub ordinal_position: i32,
+
+    /// Data type of the attribute as formatted by format_type, including any type modifiers and array brackets
+    pub data_type: String,
+
+    /// Name of the schema that contains the data type of the attribute, or of its elements if it is an array
+    pub attribute_type_schema: String,
+
+    /// Name of the data type of the attribute, or of its elements if it is an array
+    pub attribute_type_name: String,
+}
+
+/// Column properties that information_schema.columns does not expose, read from pg_attribute. One row per column of every table, view and foreign table.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ColumnAttribute {
+    /// Name of the schema containing the table
+    pub table_schema: String,
+
+    /// Name of the table
+    pub table_name: String,
+
+    /// Name of the column
+    pub column_name: String,
+
+    /// Number of dimensions, if the column is an array type; otherwise 0. (Presently, the number of dimensions of an array is not enforced, so any nonzero value effectively means “it's an array”.)
+    pub array_dimensions: i32,
+
+    /// If the column is an array of a character or bit string type, the declared maximum length of the elements; null otherwise. information_schema.element_types always leaves this null.
+    pub element_character_maximum_length: Option<i32>,
+
+    /// If the column is an array of a numeric type, the declared or implicit precision of the elements; null otherwise.
+    pub element_numeric_precision: Option<i32>,
+
+    /// If the column is an array of an exact numeric type, the declared or implicit scale of the elements; null otherwise.
+    pub element_numeric_scale: Option<i32>,
+
+    /// If the column is an array of a date, time, timestamp, or interval type, the fractional seconds precision of the elements; null otherwise.
+    pub element_datetime_precision: Option<i32>,
+
+    /// If the column is an array of an interval type with field restrictions, the fields of the elements; null otherwise.
+    pub element_interval_type: Option<String>,
+
+    /// This column is defined locally in the relation. Note that a column can be locally defined and inherited simultaneously.
+    pub is_local: bool,
+
+    /// The number of direct ancestors this column has. A column with a nonzero number of ancestors cannot be dropped nor renamed.
+    pub inherited_count: i32,
+
+    /// If true, the column has the collation of its data type (for a domain, the collation of the domain), so there is nothing to declare
+    pub has_default_collation: bool,
+
+    /// This column has a not-null constraint of its own. Unlike information_schema.columns.is_nullable, this is false for a column that is only not null because its domain is
+    pub is_not_null: bool,
+}
+
+/// Sequences read from pg_sequence, together with the column that owns them (if any) from pg_depend. One row per sequence.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Sequence {
+    /// Name of the schema that contains the sequence
+    pub sequence_schema: String,
+
+    /// Name of the sequence
+    pub sequence_name: String,
+
+    /// Data type of the sequence (smallint, integer or bigint)
+    pub data_type: String,
+
+    /// The start value of the sequence
+    pub start_value: i64,
+
+    /// The increment of the sequence
+    pub increment: i64,
+
+    /// The minimum value of the sequence
+    pub minimum_value: i64,
+
+    /// The maximum value of the sequence
+    pub maximum_value: i64,
+
+    /// The number of sequence values that are preallocated
+    pub cache_size: i64,
+
+    /// If true, the sequence wraps around once it reaches its limit
+    pub cycle_option: bool,
+
+    /// Name of the schema containing the table of the owning column, null if the sequence is not owned by a column
+    pub owned_by_table_schema: Option<String>,
+
+    /// Name of the table of the owning column, null if the sequence is not owned by a column
+    pub owned_by_table_name: Option<String>,
+
+    /// Name of the owning column, null if the sequence is not owned by a column
+    pub owned_by_column_name: Option<String>,
+
+    /// If true, the sequence is the internal sequence of an identity column
+    pub is_identity: bool,
+
+    /// If true, the default of the owning column is exactly nextval() of this sequence
+    pub is_owner_default: bool,
+}
+
+impl Sequence {
+    pub fn is_system_schema(&self) -> bool {
+        is_system_schema(Some(&self.sequence_schema))
+    }
+}
+
+/// Table constraint properties that information_schema does not expose, read from pg_constraint. One row per constraint; the NOT NULL checks that information_schema.table_constraints synthesizes for every non-nullable column have no row here.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ConstraintDetail {
+    /// Name of the schema that contains the constraint
+    pub constraint_schema: String,
+
+    /// Name of the constraint
+    pub constraint_name: String,
+
+    /// Name of the schema that contains the table
+    pub table_schema: String,
+
+    /// Name of the table
+    pub table_name: String,
+
+    /// c = check constraint, f = foreign key constraint, p = primary key constraint, u = unique constraint, t = constraint trigger, x = exclusion constraint
+    pub constraint_type: String,
+
+    /// Expression of a check constraint, without the surrounding CHECK, null for other constraint types
+    pub check_expression: Option<String>,
+
+    /// If true, the constraint is local to the table and is not inherited by child tables (NO INHERIT)
+    pub is_no_inherit: bool,
+
+    /// If false, the constraint was added with NOT VALID and has not been validated yet
+    pub is_validated: bool,
+
+    /// The column a check constraint's expression uses, which postgres names the constraint after; null if it uses none or several, and for other constraints
+    pub check_column: Option<String>,
+
+    /// Columns a foreign key references, in the order they pair up with its own columns; null for other constraints
+    pub referenced_columns: Option<Vec<String>>,
+
+    /// Columns a foreign key's ON DELETE SET NULL / SET DEFAULT action is limited to, null when it covers all the key columns
+    pub delete_set_columns: Option<Vec<String>>,
+
+    /// Schema qualified operators of an exclusion constraint, one per key column of its index
+    pub exclusion_operators: Option<Vec<String>>,
+
+    /// If true, the constraint is deferrable
+    pub is_deferrable: bool,
+
+    /// If true, the constraint is deferred by default
+    pub is_initially_deferred: bool,
+
+    /// This constraint is defined locally for the relation. Note that a constraint can be locally defined and inherited simultaneously.
+    pub is_local: bool,
+
+    /// The number of direct inheritance ancestors this constraint has. A constraint with a nonzero number of ancestors cannot be dropped nor renamed.
+    pub inherited_count: i32,
+}
+
+/// Partitioned tables read from pg_partitioned_table. One row per table declared with PARTITION BY.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct PartitionedTable {
+    /// Name of the schema that contains the table
+    pub table_schema: String,
+
+    /// Name of the table
+    pub table_name: String,
+
+    /// Partitioning strategy and key as written after PARTITION BY, for example RANGE (created_at)
+    pub partition_key: String,
+}
+
+/// Parents of tables that are partitions or inherit from other tables, read from pg_inherits. One row per table and parent.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct TableInheritance {
+    /// Name of the schema that contains the child table
+    pub table_schema: String,
+
+    /// Name of the child table
+    pub table_name: String,
+
+    /// Name of the schema that contains the parent table
+    pub parent_schema: String,
+
+    /// Name of the parent table
+    pub parent_name: String,
+
+    /// If there is more than one direct parent for a child table (multiple inheritance), this number tells the order in which the inherited columns are to be arranged. The count starts at 1.
+    pub sequence_number: i32,
+
+    /// Partition bound as written after PARTITION OF parent, for example FOR VALUES IN (1) or DEFAULT; null if the table is not a partition
+    pub partition_bound: Option<String>,
+}
+
+/// Foreign-data wrappers read from pg_foreign_data_wrapper, since information_schema.foreign_data_wrappers leaves out the handler and validator functions. Wrappers that belong to an extension are left out, they come with the extension.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ForeignDataWrapper {
+    /// Name of the foreign-data wrapper
+    pub foreign_data_wrapper_name: String,
+
+    /// Handler function of the foreign-data wrapper, null if it has none
+    pub handler: Option<String>,
+
+    /// Validator function of the foreign-data wrapper, null if it has none
+    pub validator: Option<String>,
+}
+
+/// The view foreign_data_wrapper_options contains all the options defined for foreign-data wrappers in the current database. Only those foreign-data wrappers are shown that the current user has access to (by way of being the owner or having some privilege).
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ForeignDataWrapperOption {
+    /// Name of the database that the foreign-data wrapper is defined in (always the current database)
+    pub foreign_data_wrapper_catalog: Option<String>,
+
+    /// Name of the foreign-data wrapper
+    pub foreign_data_wrapper_name: String,
+
+    /// Name of an option
+    pub option_name: String,
+
+    /// Value of the option
+    pub option_value: Option<String>,
+}
+
+/// The view foreign_servers contains all foreign servers defined in the current database. Only those foreign servers are shown that the current user has access to (by way of being the owner or having some privilege).
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ForeignServer {
+    /// Name of the database that the foreign server is defined in (always the current database)
+    pub foreign_server_catalog: Option<String>,
+
+    /// Name of the foreign server
+    pub foreign_server_name: String,
+
+    /// Name of the database that contains the foreign-data wrapper used by the foreign server (always the current database)
+    pub foreign_data_wrapper_catalog: Option<String>,
+
+    /// Name of the foreign-data wrapper used by the foreign server
+    pub foreign_data_wrapper_name: String,
+
+    /// Foreign server type information, if specified upon creation
+    pub foreign_server_type: Option<String>,
+
+    /// Foreign server version information, if specified upon creation
+    pub foreign_server_version: Option<String>,
+
+    /// Name of the owner of the foreign server
+    pub authorization_identifier: Option<String>,
+}
+
+/// The view foreign_server_options contains all the options defined for foreign servers in the current database. Only those foreign servers are shown that the current user has access to (by way of being the owner or having some privilege).
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ForeignServerOption {
+    /// Name of the database that the foreign server is defined in (always the current database)
+    pub foreign_server_catalog: Option<String>,
+
+    /// Name of the foreign server
+    pub foreign_server_name: String,
+
+    /// Name of an option
+    pub option_name: String,
+
+    /// Value of the option
+    pub option_value: Option<String>,
+}
+
+/// The view foreign_tables contains all foreign tables defined in the current database. Only those foreign tables are shown that the current user has access to (by way of being the owner or having some privilege).
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ForeignTable {
+    /// Name of the database that the foreign table is defined in (always the current database)
+    pub foreign_table_catalog: Option<String>,
+
+    /// Name of the schema that contains the foreign table
+    pub foreign_table_schema: String,
+
+    /// Name of the foreign table
+    pub foreign_table_name: String,
+
+    /// Name of the database that the foreign server is defined in (always the current database)
+    pub foreign_server_catalog: Option<String>,
+
+    /// Name of the foreign server
+    pub foreign_server_name: String,
+}
+
+/// The view foreign_table_options contains all the options defined for foreign tables in the current database. Only those foreign tables are shown that the current user has access to (by way of being the owner or having some privilege).
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ForeignTableOption {
+    /// Name of the database that contains the foreign table (always the current database)
+    pub foreign_table_catalog: Option<String>,
+
+    /// Name of the schema that contains the foreign table
+    pub foreign_table_schema: String,
+
+    /// Name of the foreign table
+    pub foreign_table_name: String,
+
+    /// Name of an option
+    pub option_name: String,
+
+    /// Value of the option
+    pub option_value: Option<String>,
+}
+
+/// The view column_options contains all the options defined for foreign table columns in the current database. Only those foreign table columns are shown that the current user has access to (by way of being the owner or having some privilege).
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ColumnOption {
+    /// Name of the database that contains the foreign table (always the current database)
+    pub table_catalog: Option<String>,
+
+    /// Name of the schema that contains the foreign table
+    pub table_schema: String,
+
+    /// Name of the foreign table
+    pub table_name: String,
+
+    /// Name of the column
+    pub column_name: String,
+
+    /// Name of an option
+    pub option_name: String,
+
+    /// Value of the option
+    pub option_value: Option<String>,
+}
+
+/// The view user_mappings contains all user mappings defined in the current database. Only those user mappings are shown where the current user has access to the corresponding foreign server (by way of being the owner or having some privilege).
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct UserMapping {
+    /// Name of the user being mapped, or PUBLIC if the mapping is public
+    pub authorization_identifier: String,
+
+    /// Name of the database that the foreign server used by this mapping is defined in (always the current database)
+    pub foreign_server_catalog: Option<String>,
+
+    /// Name of the foreign server used by this mapping
+    pub foreign_server_name: String,
+}
+
+/// The view user_mapping_options contains all the options defined for user mappings in the current database. Only those user mappings are shown where the current user has access to the corresponding foreign server (by way of being the owner or having some privilege).
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct UserMappingOption {
+    /// Name of the user being mapped, or PUBLIC if the mapping is public
+    pub authorization_identifier: String,
+
+    /// Name of the database that the foreign server used by this mapping is defined in (always the current database)
+    pub foreign_server_catalog: Option<String>,
+
+    /// Name of the foreign server used by this mapping
+    pub foreign_server_name: String,
+
+    /// Name of an option
+    pub option_name: String,
+
+    /// Value of the option. This column will show as null unless the current user is the user being mapped, or the mapping is for PUBLIC and the current user is the server owner, or the current user is a superuser. The intent is to protect password information stored as user mapping option.
+    pub option_value: Option<String>,
+}
+
+/// The view pg_matviews provides access to useful information about each materialized view in the database.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct MaterializedView {
+    /// Name of schema containing materialized view
+    pub schemaname: String,
+
+    /// Name of materialized view
+    pub matviewname: String,
+
+    /// Name of materialized view's owner
+    pub matviewowner: Option<String>,
+
+    /// Name of tablespace containing materialized view (null if default for database)
+    pub tablespace: Option<String>,
+
+    /// True if materialized view has (or recently had) any indexes
+    pub hasindexes: bool,
+
+    /// True if materialized view is currently populated
+    pub ispopulated: bool,
+
+    /// Materialized view definition (a reconstructed SELECT query)
+    pub definition: Option<String>,
+}
+
+impl MaterializedView {
+    pub fn is_system_schema(&self) -> bool {
+        is_system_schema(Some(&self.schemaname))
+    }
+}
+
+/// The dependencies information_schema.view_table_usage leaves out because a materialized view is on one side of them: the relations a materialized view selects from, and the views that select from a materialized view. Read from pg_depend.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct MaterializedViewUsage {
+    /// Name of the schema that contains the view or materialized view
+    pub view_schema: String,
+
+    /// Name of the view or materialized view
+    pub view_name: String,
+
+    /// Name of the schema that contains the relation that is used by the view
+    pub table_schema: String,
+
+    /// Name of the relation that is used by the view
+    pub table_name: String,
+}
+
+/// Functions, procedures and window functions read from pg_proc. One row per routine; routines that belong to an extension are left out, they come with the extension.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Routine {
+    /// Name of the schema that contains the routine
+    pub routine_schema: String,
+
+    /// Name of the routine
+    pub routine_name: String,
+
+    /// Kind of the routine: f for a normal function, p for a procedure, w for a window function
+    pub kind: String,
+
+    /// Argument list as it would appear in CREATE FUNCTION, including defaults
+    pub arguments: String,
+
+    /// Argument list that identifies the routine, as it would appear in ALTER FUNCTION
+    pub identity_arguments: String,
+
+    /// RETURNS clause as it would appear in CREATE FUNCTION, null for procedures
+    pub result: Option<String>,
+
+    /// Name of the language the routine is written in
+    pub language: String,
+
+    /// Complete CREATE OR REPLACE statement for the routine, as reconstructed by pg_get_functiondef
+    pub definition: String,
+}
+
+impl Routine {
+    pub fn is_system_schema(&self) -> bool {
+        is_system_schema(Some(&self.routine_schema))
+    }
+}
+
+/// The types a routine's arguments and result are declared with, read from pg_depend. For an array, the type of its elements. One row per routine and type.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct RoutineTypeUsage {
+    /// Name of the schema that contains the routine
+    pub routine_schema: String,
+
+    /// Name of the routine
+    pub routine_name: String,
+
+    /// Argument list that identifies the routine, as it would appear in ALTER FUNCTION
+    pub identity_arguments: String,
+
+    /// Name of the schema that contains the type
+    pub type_schema: String,
+
+    /// Name of the type
+    pub type_name: String,
+
+    /// If true, the type is the row type of a table, view or other relation rather than a standalone composite type
+    pub is_relation_row_type: bool,
+}
+
+/// The routines a domain's default and check constraints call, read from pg_depend. One row per domain and routine.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct DomainRoutineUsage {
+    /// Name of the schema that contains the domain
+    pub domain_schema: String,
+
+    /// Name of the domain
+    pub domain_name: String,
+
+    /// Name of the schema that contains the routine
+    pub routine_schema: String,
+
+    /// Name of the routine
+    pub routine_name: String,
+
+    /// Argument list that identifies the routine, as it would appear in ALTER FUNCTION
+    pub identity_arguments: String,
+}
+
+/// Aggregate functions read from pg_proc and pg_aggregate. One row per aggregate; aggregates that belong to an extension are left out, they come with the extension.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Aggregate {
+    /// Name of the schema that contains the aggregate
+    pub aggregate_schema: String,
+
+    /// Name of the aggregate
+    pub aggregate_name: String,
+
+    /// Kind of the aggregate: n for normal aggregates, o for ordered-set aggregates, h for hypothetical-set aggregates
+    pub kind: String,
+
+    /// Argument list as it would appear in CREATE AGGREGATE, with ORDER BY for ordered-set aggregates
+    pub arguments: String,
+
+    /// Argument list that identifies the aggregate, as it would appear in ALTER AGGREGATE
+    pub identity_arguments: String,
+
+    /// Transition function
+    pub transition_function: String,
+
+    /// Data type of the aggregate's internal transition (state) data
+    pub transition_type: String,
+
+    /// Approximate average size (in bytes) of the transition state data, or zero to use a default estimate
+    pub transition_space: i32,
+
+    /// Final function (null if none)
+    pub final_function: Option<String>,
+
+    /// True to pass extra dummy arguments to the final function
+    pub final_function_extra: bool,
+
+    /// Whether the final function modifies the transition state value: r if it is read-only, s if the transition function cannot be applied after it, w if it writes on the value
+    pub final_function_modify: String,
+
+    /// Combine function (null if none)
+    pub combine_function: Option<String>,
+
+    /// Serialization function (null if none)
+    pub serial_function: Option<String>,
+
+    /// Deserialization function (null if none)
+    pub deserial_function: Option<String>,
+
+    /// The initial value of the transition state, as text (null if none)
+    pub initial_condition: Option<String>,
+
+    /// Forward transition function for moving-aggregate mode (null if none)
+    pub moving_transition_function: Option<String>,
+
+    /// Inverse transition function for moving-aggregate mode (null if none)
+    pub moving_inverse_function: Option<String>,
+
+    /// Data type of the aggregate's internal transition (state) data for moving-aggregate mode (null if none)
+    pub moving_transition_type: Option<String>,
+
+    /// Approximate average size (in bytes) of the transition state data for moving-aggregate mode, or zero to use a default estimate
+    pub moving_transition_space: i32,
+
+    /// Final function for moving-aggregate mode (null if none)
+    pub moving_final_function: Option<String>,
+
+    /// True to pass extra dummy arguments to the moving-aggregate mode final function
+    pub moving_final_function_extra: bool,
+
+    /// Like final_function_modify, but for the moving-aggregate mode final function
+    pub moving_final_function_modify: String,
+
+    /// The initial value of the transition state for moving-aggregate mode, as text (null if none)
+    pub moving_initial_condition: Option<String>,
+
+    /// Associated sort operator, schema qualified (null if none)
+    pub sort_operator: Option<String>,
+
+    /// Whether the aggregate is safe to run in parallel mode: s for safe, r for restricted, u for unsafe
+    pub parallel: String,
+}
+
+impl Aggregate {
+    pub fn is_system_schema(&self) -> bool {
+        is_system_schema(Some(&self.aggregate_schema))
+    }
+}
+
+/// Extensions read from pg_extension. One row per installed extension.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Extension {
+    /// Name of the extension
+    pub extension_name: String,
+
+    /// Name of the schema that contains the extension's exportable objects
+    pub extension_schema: String,
+
+    /// Version name for the extension
+    pub extension_version: String,
+
+    /// Names of the extensions this extension requires
+    pub requires: Vec<String>,
+}
+
+impl Extension {
+    pub fn is_system_schema(&self) -> bool {
+        is_system_schema(Some(&self.extension_schema))
+    }
+}
+
+/// Relations and types that belong to an extension, read from pg_depend. These come with the extension and are left out of the rest of the output.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ExtensionMember {
+    /// Name of the extension the object belongs to
+    pub extension_name: String,
+
+    /// Name of the schema that contains the object
+    pub object_schema: String,
+
+    /// Name of the object
+    pub object_name: String,
+
+    /// Kind of the object: relation for tables, views, materialized views and sequences, type for types
+    pub object_type: String,
+}
+
+/// Privileges on tables, columns, sequences, schemas and functions that differ from what the object starts out with: the owner's implicit privileges, or the initial privileges postgres records for objects it creates itself. Read from the objects' acl columns. Objects that belong to an extension are left out.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Privilege {
+    /// Kind of the object, as it appears in GRANT: TABLE, SEQUENCE, COLUMN, SCHEMA, FUNCTION or PROCEDURE
+    pub object_type: String,
+
+    /// Name of the schema that contains the object (the schema itself for schemas)
+    pub object_schema: String,
+
+    /// Name of the object (the table for columns)
+    pub object_name: String,
+
+    /// Name of the column, for column privileges
+    pub column_name: Option<String>,
+
+    /// Argument list that identifies the function, for function privileges
+    pub identity_arguments: Option<String>,
+
+    /// Name of the role the privilege is granted to or revoked from, null for PUBLIC
+    pub grantee: Option<String>,
+
+    /// Type of the privilege: SELECT, INSERT, UPDATE, DELETE, TRUNCATE, REFERENCES, TRIGGER, USAGE, CREATE or EXECUTE
+    pub privilege_type: String,
+
+    /// True if the privilege is granted with grant option
+    pub is_grantable: bool,
+
+    /// True if the privilege has to be granted, false if it has to be revoked
+    pub is_grant: bool,
+}
+
+impl Privilege {
+    pub fn is_system_schema(&self) -> bool {
+        is_system_schema(Some(&self.object_schema))
+    }
+}
+
+/// Default privileges set with ALTER DEFAULT PRIVILEGES, as far as they differ from the built-in defaults. Read from pg_default_acl.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct DefaultPrivilege {
+    /// Name of the role whose newly created objects get the privileges
+    pub role_name: String,
+
+    /// Name of the schema the default privileges are limited to, null if they apply everywhere
+    pub schema_name: Option<String>,
+
+    /// Kind of the objects, as it appears in ALTER DEFAULT PRIVILEGES: TABLES, SEQUENCES, FUNCTIONS, TYPES or SCHEMAS
+    pub object_type: String,
+
+    /// Name of the role the privilege is granted to or revoked from, null for PUBLIC
+    pub grantee: Option<String>,
+
+    /// Type of the privilege
+    pub privilege_type: String,
+
+    /// True if the privilege is granted with grant option
+    pub is_grantable: bool,
+
+    /// True if the privilege has to be granted, false if it has to be revoked
+    pub is_grant: bool,
+}
+
+/// Tables with row level security turned on, read from pg_class. Tables that have it off are left out.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct RowSecurity {
+    /// Name of the schema that contains the table
+    pub table_schema: String,
+
+    /// Name of the table
+    pub table_name: String,
+
+    /// True if row level security is enabled on the table
+    pub is_enabled: bool,
+
+    /// True if row level security also applies to the table's owner
+    pub is_forced: bool,
+}
+
+/// The view pg_policies provides access to useful information about each row-level security policy in the database.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Policy {
+    /// Name of schema containing table policy is on
+    pub schemaname: String,
+
+    /// Name of table policy is on
+    pub tablename: String,
+
+    /// Name of policy
+    pub policyname: String,
+
+    /// Is the policy permissive or restrictive?
+    pub permissive: String,
+
+    /// The roles to which this policy applies
+    pub roles: Vec<String>,
+
+    /// The command type to which the policy is applied
+    pub cmd: String,
+
+    /// The expression added to the security barrier qualifications for queries that this policy applies to
+    pub qual: Option<String>,
+
+    /// The expression added to the WITH CHECK qualifications for queries that attempt to add rows to this table
+    pub with_check: Option<String>,
+}
+
+/// Comments set with COMMENT ON, read from pg_description. One row per commented table, view, column, constraint, type or function.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Comment {
+    /// Kind of the object: relation for tables, views and materialized views, column, constraint for table constraints, domain constraint, type, or function for functions, procedures and aggregates
+    pub object_type: String,
+
+    /// Name of the schema that contains the object
+    pub object_schema: String,
+
+    /// Name of the object (the table or domain for columns and constraints)
+    pub object_name: String,
+
+    /// Name of the column or constraint, null for other objects
+    pub member_name: Option<String>,
+
+    /// Argument list that identifies the function, null for other objects
+    pub identity_arguments: Option<String>,
+
+    /// The comment
+    pub description: String,
+}
@@ -1,15 +1,10 @@
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
 pub enum YesNo {
+    #[default]
     No,
     Yes,
 }
 
-impl Default for YesNo {
-    fn default() -> Self {
-        Self::No
-    }
-}
-
 impl YesNo {
     /// Returns `true` if the yes no is [`Yes`].
     ///
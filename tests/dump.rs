@@ -0,0 +1,382 @@
+//! runs dbstate against a scratch database and checks what it prints. the
+//! scratch databases are created next to the one at DATABASE_URL; without
+//! DATABASE_URL the tests are skipped.
+
+use std::{env, process::Command};
+
+use sqlx::{Executor, PgPool};
+
+/// creates the database `dbstate_test_<name>`, runs `setup` in it, and
+/// returns what dbstate prints for it. none if DATABASE_URL is not set.
+async fn dump(name: &str, setup: &str) -> Option<String> {
+    let url = match env::var("DATABASE_URL") {
+        Ok(url) => url,
+        Err(_) => {
+            eprintln!("DATABASE_URL is not set, skipping");
+            return None;
+        }
+    };
+    let database = format!("dbstate_test_{}", name);
+
+    let pool = PgPool::connect(&url).await.unwrap();
+    let drop_database = format!("drop database if exists {} with (force)", database);
+    pool.execute(drop_database.as_str()).await.unwrap();
+    pool.execute(format!("create database {}", database).as_str())
+        .await
+        .unwrap();
+
+    let test_url = database_url(&url, &database);
+    let test_pool = PgPool::connect(&test_url).await.unwrap();
+    test_pool.execute(setup).await.unwrap();
+    test_pool.close().await;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_dbstate"))
+        .env("DATABASE_URL", &test_url)
+        .output()
+        .unwrap();
+
+    pool.execute(drop_database.as_str()).await.unwrap();
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Some(String::from_utf8(output.stdout).unwrap())
+}
+
+/// `url` with its database swapped for `database`
+fn database_url(url: &str, database: &str) -> String {
+    let (base, query) = match url.split_once('?') {
+        Some((base, query)) => (base, Some(query)),
+        None => (url, None),
+    };
+    let (server, _) = base.rsplit_once('/').unwrap_or((base, ""));
+
+    match query {
+        Some(query) => format!("{}/{}?{}", server, database, query),
+        None => format!("{}/{}", server, database),
+    }
+}
+
+#[tokio::test]
+async fn composite_type_with_domain_over_array_attribute() {
+    let Some(output) = dump(
+        "composite_domain_array",
+        r#"
+        create domain dd as varchar(5)[];
+        create type ct as (a dd, b numeric(4,1));
+        "#,
+    )
+    .await
+    else {
+        return;
+    };
+
+    assert!(
+        output.contains("CREATE DOMAIN \"dd\" AS character varying(5)[];"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("CREATE TYPE \"ct\" AS (\n\t\"a\" dd,\n\t\"b\" numeric(4,1)\n);"),
+        "{}",
+        output
+    );
+}
+
+#[tokio::test]
+async fn foreign_key_with_delete_set_columns() {
+    let Some(output) = dump(
+        "delete_set_columns",
+        r#"
+        create table p (a int, b int, primary key (a, b));
+        create table c (a int, b int, foreign key (a, b) references p on delete set null (b));
+        create table d (a int, b int, foreign key (a, b) references p on delete set null);
+        "#,
+    )
+    .await
+    else {
+        return;
+    };
+
+    assert!(
+        output.contains("REFERENCES \"p\" (\"a\", \"b\") ON DELETE SET NULL (\"b\")"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("REFERENCES \"p\" (\"a\", \"b\") ON DELETE SET NULL;\n"),
+        "{}",
+        output
+    );
+}
+
+#[tokio::test]
+async fn column_of_not_null_domain() {
+    let Some(output) = dump(
+        "not_null_domain",
+        r#"
+        create domain nn as int not null;
+        create table t (a nn, b nn not null, c int not null);
+        "#,
+    )
+    .await
+    else {
+        return;
+    };
+
+    assert!(output.contains("\n\t\"a\" \"nn\",\n"), "{}", output);
+    assert!(
+        output.contains("\n\t\"b\" \"nn\" NOT NULL,\n"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("\n\t\"c\" integer NOT NULL\n"),
+        "{}",
+        output
+    );
+}
+
+#[tokio::test]
+async fn domains_and_composite_types_in_dependency_order() {
+    let Some(output) = dump(
+        "type_order",
+        r#"
+        create type a_pair as (x int, y int);
+        create domain b_pairs as a_pair[];
+        create type c_shape as (points b_pairs);
+        "#,
+    )
+    .await
+    else {
+        return;
+    };
+
+    let pair = output.find("CREATE TYPE \"a_pair\"");
+    let pairs = output.find("CREATE DOMAIN \"b_pairs\"");
+    let shape = output.find("CREATE TYPE \"c_shape\"");
+    assert!(
+        pair.is_some() && pair < pairs && pairs < shape,
+        "{}",
+        output
+    );
+}
+
+#[tokio::test]
+async fn materialized_view_column_comments() {
+    let Some(output) = dump(
+        "matview_comments",
+        r#"
+        create table t (id int);
+        create materialized view mv as select id from t;
+        comment on materialized view mv is 'the view';
+        comment on column mv.id is 'the id';
+        "#,
+    )
+    .await
+    else {
+        return;
+    };
+
+    assert!(
+        output.contains("COMMENT ON MATERIALIZED VIEW \"mv\" IS 'the view';"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("COMMENT ON COLUMN \"mv\".\"id\" IS 'the id';"),
+        "{}",
+        output
+    );
+}
+
+#[tokio::test]
+async fn truncate_and_constraint_triggers() {
+    let Some(output) = dump(
+        "triggers",
+        r#"
+        create table t (id int);
+        create function f() returns trigger language plpgsql as $$ begin return null; end $$;
+        create trigger tt before truncate on t for each statement execute function f();
+        create trigger ti before insert or truncate on t for each statement execute function f();
+        create constraint trigger ct after insert on t
+            deferrable initially deferred
+            for each row execute function f();
+        "#,
+    )
+    .await
+    else {
+        return;
+    };
+
+    assert!(
+        output.contains(
+            "CREATE TRIGGER tt BEFORE TRUNCATE ON t FOR EACH STATEMENT EXECUTE FUNCTION f();"
+        ),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("CREATE TRIGGER ti BEFORE INSERT OR TRUNCATE ON t"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains(
+            "CREATE CONSTRAINT TRIGGER ct AFTER INSERT ON t DEFERRABLE INITIALLY DEFERRED FOR EACH ROW"
+        ),
+        "{}",
+        output
+    );
+}
+
+#[tokio::test]
+async fn routines_around_the_types_and_tables_they_use() {
+    let Some(output) = dump(
+        "routine_order",
+        r#"
+        create function is_even(i int) returns boolean language sql immutable
+            as $$ select i % 2 = 0 $$;
+        create domain even as int check (is_even(value));
+        create table people (id int primary key, name text);
+        create function everyone() returns setof people language sql
+            as $$ select * from people $$;
+        "#,
+    )
+    .await
+    else {
+        return;
+    };
+
+    let set = output.find("SET check_function_bodies = false;");
+    let is_even = output.find("FUNCTION public.is_even(");
+    let even = output.find("CREATE DOMAIN \"even\"");
+    let people = output.find("CREATE TABLE \"people\"");
+    let everyone = output.find("FUNCTION public.everyone()");
+    let reset = output.find("RESET check_function_bodies;");
+    assert!(
+        set.is_some() && set < is_even && is_even < even && even < people,
+        "{}",
+        output
+    );
+    assert!(people < everyone && everyone < reset, "{}", output);
+}
+
+#[tokio::test]
+async fn inherited_columns_with_their_own_not_null_and_default() {
+    let Some(output) = dump(
+        "inherited_column_options",
+        r#"
+        create table base (name text, note text default 'b');
+        create table child (extra int) inherits (base);
+        alter table child alter name set not null;
+        alter table child alter name set default 'c';
+        alter table child alter note drop default;
+        "#,
+    )
+    .await
+    else {
+        return;
+    };
+
+    assert!(
+        output.contains(concat!(
+            "ALTER TABLE ONLY \"child\" ALTER COLUMN \"name\" SET NOT NULL;\n",
+            "ALTER TABLE ONLY \"child\" ALTER COLUMN \"name\" SET DEFAULT 'c'::text;\n",
+            "ALTER TABLE ONLY \"child\" ALTER COLUMN \"note\" DROP DEFAULT;\n",
+        )),
+        "{}",
+        output
+    );
+}
+
+#[tokio::test]
+async fn foreign_key_pairs_columns_as_declared() {
+    let Some(output) = dump(
+        "foreign_key_pairing",
+        r#"
+        create table d (a int, b int, primary key (b, a));
+        create table e (x int, y int, foreign key (y, x) references d (b, a));
+        "#,
+    )
+    .await
+    else {
+        return;
+    };
+
+    assert!(
+        output.contains("FOREIGN KEY (\"y\", \"x\") REFERENCES \"d\" (\"b\", \"a\")"),
+        "{}",
+        output
+    );
+}
+
+#[tokio::test]
+async fn foreign_keys_added_after_the_tables() {
+    let Some(output) = dump(
+        "foreign_key_order",
+        r#"
+        create table people (id int primary key);
+        create table fk1 (p int references people);
+        "#,
+    )
+    .await
+    else {
+        return;
+    };
+
+    let fk1 = output.find("CREATE TABLE \"fk1\"");
+    let people = output.find("CREATE TABLE \"people\"");
+    let foreign_key =
+        output.find("ALTER TABLE \"fk1\" ADD FOREIGN KEY (\"p\") REFERENCES \"people\" (\"id\");");
+    assert!(
+        fk1.is_some() && people.is_some() && fk1.max(people) < foreign_key,
+        "{}",
+        output
+    );
+}
+
+#[tokio::test]
+async fn numbered_default_constraint_names() {
+    let Some(output) = dump(
+        "numbered_names",
+        r#"
+        create table t (a int, b int check (b > 0), c int unique references t (c));
+        alter table t add check (b > 0);
+        alter table t add foreign key (c) references t (c);
+        alter table t add constraint t_b_check3 check (b > 1);
+        create table t_a_key (id int);
+        alter table t add unique (a);
+        "#,
+    )
+    .await
+    else {
+        return;
+    };
+
+    assert!(
+        output.contains("\tCHECK (b > 0),\n\tCHECK (b > 0),\n"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("\tCONSTRAINT \"t_b_check3\" CHECK (b > 1),\n"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("\tCONSTRAINT \"t_a_key1\" UNIQUE (\"a\"),\n"),
+        "{}",
+        output
+    );
+    assert_eq!(
+        output
+            .matches("ALTER TABLE \"t\" ADD FOREIGN KEY (\"c\") REFERENCES \"t\" (\"c\");\n")
+            .count(),
+        2,
+        "{}",
+        output
+    );
+}